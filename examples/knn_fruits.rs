@@ -0,0 +1,39 @@
+use algo_examples::formatting::on_screen_len;
+use algo_examples::ml::knn::KnnClassifier;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Fruit {
+    Orange,
+    Grapefruit,
+}
+
+fn main() {
+    // Features: size (cm) and redness (0..10).
+    let training = [
+        ([7.0, 3.0], Fruit::Orange),
+        ([7.5, 2.0], Fruit::Orange),
+        ([6.5, 3.5], Fruit::Orange),
+        ([8.0, 2.5], Fruit::Orange),
+        ([12.0, 7.0], Fruit::Grapefruit),
+        ([11.5, 8.0], Fruit::Grapefruit),
+        ([13.0, 6.5], Fruit::Grapefruit),
+        ([12.5, 7.5], Fruit::Grapefruit),
+    ];
+    let (features, labels) = training.iter().map(|(f, l)| (f.to_vec(), *l)).unzip();
+
+    let mut knn = KnnClassifier::new(3);
+    knn.fit(features, labels);
+
+    let mystery_fruit = [11.0, 6.0];
+    let expected = Fruit::Grapefruit;
+    let guess = knn.predict(&mystery_fruit).unwrap();
+
+    let print = if guess == expected {
+        format!("✨ It works! It's a {guess:?} ✅")
+    } else {
+        format!("🚧 Oh, shieeet, got {guess:?} instead of {expected:?} ❌")
+    };
+    let border_len = on_screen_len(&print) + 4;
+    let border = String::from_iter(vec!['-'; border_len]);
+    println!("{border}\n| {print} |\n{border}");
+}
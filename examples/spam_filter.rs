@@ -0,0 +1,31 @@
+use algo_examples::formatting::on_screen_len;
+use algo_examples::ml::naive_bayes::NaiveBayes;
+
+fn main() {
+    let training = [
+        ("Win a FREE iPhone now, click here", "spam"),
+        ("Cheap pills, limited offer, buy now", "spam"),
+        ("You won money! Claim your prize", "spam"),
+        ("Are we still on for lunch tomorrow?", "ham"),
+        ("Here are the notes from today's meeting", "ham"),
+        ("Can you review my pull request?", "ham"),
+    ];
+
+    let mut filter = NaiveBayes::new();
+    for (text, label) in training {
+        filter.train(text, label);
+    }
+
+    let message = "Claim your free prize now";
+    let expected = "spam";
+    let verdict = filter.predict(message).unwrap();
+
+    let print = if verdict == expected {
+        format!("✨ It works! \"{message}\" is {verdict} ✅")
+    } else {
+        format!("🚧 Oh, shieeet, got {verdict} instead of {expected} ❌")
+    };
+    let border_len = on_screen_len(&print) + 4;
+    let border = String::from_iter(vec!['-'; border_len]);
+    println!("{border}\n| {print} |\n{border}");
+}
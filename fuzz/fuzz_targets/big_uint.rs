@@ -0,0 +1,20 @@
+#![no_main]
+
+use algo_examples::math::big_uint::BigUint;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(s) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(n) = s.parse::<BigUint>() {
+        // Printing and parsing again must round-trip, leading zeroes aside.
+        let expected = match s.trim_start_matches('0') {
+            "" => "0",
+            digits => digits,
+        };
+        let printed = n.to_string();
+        assert_eq!(printed, expected);
+        assert_eq!(printed.parse::<BigUint>().ok(), Some(n));
+    }
+});
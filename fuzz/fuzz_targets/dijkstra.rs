@@ -0,0 +1,39 @@
+#![no_main]
+
+use std::collections::HashMap;
+
+use algo_examples::graphs::dijkstra::dejkstras_path;
+use algo_examples::graphs::NodeGraph;
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    edges: Vec<(u8, u8, i32)>,
+    start: u8,
+    finish: u8,
+}
+
+fuzz_target!(|input: Input| {
+    let nodes: Vec<u8> = (0..=u8::MAX).collect();
+    let mut graph: NodeGraph<&u8, i32> = HashMap::new();
+    for &(from, to, weight) in &input.edges {
+        graph.entry(&nodes[to as usize]).or_default();
+        graph
+            .entry(&nodes[from as usize])
+            .or_default()
+            .insert(&nodes[to as usize], weight);
+    }
+
+    let (start, finish) = (&nodes[input.start as usize], &nodes[input.finish as usize]);
+    if let Ok(path) = dejkstras_path(&graph, start, finish) {
+        assert_eq!(path.nodes.first(), Some(&start));
+        assert_eq!(path.nodes.last(), Some(&finish));
+        let cost: i64 = path
+            .nodes
+            .windows(2)
+            .map(|w| i64::from(graph[w[0]][w[1]]))
+            .sum();
+        assert_eq!(cost, i64::from(path.cost));
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use algo_examples::graphs::parse::try_parse_edge_list;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(graph) = try_parse_edge_list(data) {
+        // Every neighbor must have an entry of its own.
+        for neighbors in graph.values() {
+            assert!(neighbors.keys().all(|n| graph.contains_key(n)));
+        }
+    }
+});
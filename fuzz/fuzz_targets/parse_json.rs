@@ -0,0 +1,12 @@
+#![no_main]
+
+use algo_examples::graphs::parse::try_parse_json;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(graph) = try_parse_json(data) {
+        for neighbors in graph.values() {
+            assert!(neighbors.keys().all(|n| graph.contains_key(n)));
+        }
+    }
+});
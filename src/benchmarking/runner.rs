@@ -0,0 +1,451 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::str::FromStr;
+use std::time::Duration;
+
+use super::{bench_once_with, calc_iterations, Clock, DefaultClock};
+use crate::error::AlgoError;
+use crate::formatting::{escape_json, render_table};
+
+struct Bench {
+    name: String,
+    f: Box<dyn FnMut()>,
+}
+
+/// Named group of benchmarks, usually one per algorithm family.
+pub struct Suite {
+    name: String,
+    benches: Vec<Bench>,
+}
+
+impl Suite {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            benches: Vec::new(),
+        }
+    }
+
+    /// Registers `f` under `name`. Its result is passed through `black_box`,
+    /// so the work isn't optimized away.
+    pub fn bench<T, F>(mut self, name: impl Into<String>, mut f: F) -> Self
+    where
+        F: FnMut() -> T + 'static,
+    {
+        let f = Box::new(move || {
+            std::hint::black_box(f());
+        });
+        self.benches.push(Bench {
+            name: name.into(),
+            f,
+        });
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Ids of all benchmarks in the suite, `suite/bench`.
+    pub fn ids(&self) -> impl Iterator<Item = String> + '_ {
+        self.benches
+            .iter()
+            .map(|b| format!("{}/{}", self.name, b.name))
+    }
+}
+
+/// How [`run`] measures benchmarks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    /// Only benchmarks whose `suite/bench` id contains this are run.
+    pub filter: Option<String>,
+    /// Time spent running a benchmark before measuring, to warm caches and branch predictors.
+    pub warmup: Duration,
+    /// Approximate time spent measuring each benchmark.
+    pub budget: Duration,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            filter: None,
+            warmup: Duration::from_millis(200),
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Upper bound on timed samples kept per benchmark.
+const MAX_SAMPLES: u32 = 1_000;
+
+/// Result of one benchmark.
+///
+/// With more than [`MAX_SAMPLES`] iterations, iterations are timed in equal batches,
+/// and `min` and `median` are taken over the batch means.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// `suite/bench`.
+    pub id: String,
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Median of the same benchmark in a previous run, see [`apply_baseline`].
+    pub baseline: Option<Duration>,
+}
+
+impl Measurement {
+    /// Relative change of the median against the baseline, e.g. `-0.25` for 25% faster.
+    pub fn change(&self) -> Option<f64> {
+        let baseline = self.baseline?.as_secs_f64();
+        (baseline > 0.0).then(|| self.median.as_secs_f64() / baseline - 1.0)
+    }
+}
+
+fn measure<C: Clock>(clock: &C, id: String, f: &mut dyn FnMut(), options: &Options) -> Measurement {
+    let warmup_start = clock.now();
+    while clock.now().saturating_sub(warmup_start) < options.warmup {
+        f();
+    }
+
+    let one_measurement_takes = bench_once_with(clock, &mut *f);
+    let iterations = calc_iterations(one_measurement_takes, options.budget).max(1);
+    // Fast benchmarks get hundreds of millions of iterations, so they're timed in batches
+    // and memory stays bounded by `MAX_SAMPLES`.
+    let batch = iterations.div_ceil(MAX_SAMPLES);
+    let mut samples = Vec::with_capacity(iterations.div_ceil(batch) as usize);
+    let (mut left, mut total) = (iterations, Duration::ZERO);
+    while left > 0 {
+        let n = batch.min(left);
+        let took = bench_once_with(clock, || (0..n).for_each(|_| f()));
+        samples.push(took / n);
+        total += took;
+        left -= n;
+    }
+    samples.sort_unstable();
+
+    Measurement {
+        id,
+        iterations,
+        min: samples[0],
+        median: samples[samples.len() / 2],
+        mean: total / iterations,
+        baseline: None,
+    }
+}
+
+/// Runs every benchmark matching `options.filter`, in registration order.
+pub fn run(suites: &mut [Suite], options: &Options) -> Vec<Measurement> {
+    run_with(&DefaultClock::default(), suites, options)
+}
+
+/// Same as [`run`], measuring time with `clock`.
+pub fn run_with<C: Clock>(clock: &C, suites: &mut [Suite], options: &Options) -> Vec<Measurement> {
+    let mut results = Vec::new();
+    for suite in suites {
+        for bench in &mut suite.benches {
+            let id = format!("{}/{}", suite.name, bench.name);
+            if options
+                .filter
+                .as_ref()
+                .is_some_and(|f| !id.contains(f.as_str()))
+            {
+                continue;
+            }
+            results.push(measure(clock, id, &mut bench.f, options));
+        }
+    }
+    results
+}
+
+/// Output formats for [`report`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Pipe table with right-aligned numbers.
+    #[default]
+    Markdown,
+    /// Bordered table for the terminal, see [`render_table`].
+    Table,
+    Json,
+    /// Can be read back by [`parse_baseline`].
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = AlgoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "markdown" | "md" => Ok(Self::Markdown),
+            "table" => Ok(Self::Table),
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            other => Err(AlgoError::InvalidInput(format!("unknown format '{other}'"))),
+        }
+    }
+}
+
+/// Quotes a CSV field if it contains a comma, a quote or a line break.
+fn csv_field(s: &str) -> Cow<'_, str> {
+    if s.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", s.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
+/// Splits a CSV line written with [`csv_field`]s.
+fn csv_fields(line: &str) -> Vec<String> {
+    let (mut fields, mut field, mut quoted) = (Vec::new(), String::new(), false);
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+const HEADERS: [&str; 6] = ["benchmark", "iterations", "min", "median", "mean", "change"];
+
+/// Cells of a human-readable report, one row per result in [`HEADERS`] order.
+fn rows(results: &[Measurement]) -> Vec<Vec<String>> {
+    results
+        .iter()
+        .map(|m| {
+            let change = m
+                .change()
+                .map_or(String::new(), |c| format!("{:+.1}%", c * 100.0));
+            vec![
+                m.id.clone(),
+                m.iterations.to_string(),
+                format!("{:?}", m.min),
+                format!("{:?}", m.median),
+                format!("{:?}", m.mean),
+                change,
+            ]
+        })
+        .collect()
+}
+
+/// Renders results for humans (markdown or a table) or other tools (JSON, CSV).
+/// Times are in nanoseconds for JSON and CSV.
+pub fn report(results: &[Measurement], format: Format) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Markdown => {
+            writeln!(out, "| {} |", HEADERS.join(" | ")).unwrap();
+            out.push_str("|---|---:|---:|---:|---:|---:|\n");
+            for row in rows(results) {
+                let cells: Vec<String> = row.iter().map(|c| c.replace('|', "\\|")).collect();
+                writeln!(out, "| {} |", cells.join(" | ")).unwrap();
+            }
+        }
+        Format::Table => {
+            out = render_table(&HEADERS, &rows(results));
+            out.push('\n');
+        }
+        Format::Json => {
+            let entries: Vec<String> = results
+                .iter()
+                .map(|m| {
+                    let baseline = m.baseline.map_or("null".to_owned(), |b| b.as_nanos().to_string());
+                    format!(
+                        r#"{{"id":"{}","iterations":{},"min_ns":{},"median_ns":{},"mean_ns":{},"baseline_ns":{baseline}}}"#,
+                        escape_json(&m.id),
+                        m.iterations,
+                        m.min.as_nanos(),
+                        m.median.as_nanos(),
+                        m.mean.as_nanos()
+                    )
+                })
+                .collect();
+            write!(out, "[{}]", entries.join(",")).unwrap();
+        }
+        Format::Csv => {
+            out.push_str("id,iterations,min_ns,median_ns,mean_ns\n");
+            for m in results {
+                writeln!(
+                    out,
+                    "{},{},{},{},{}",
+                    csv_field(&m.id),
+                    m.iterations,
+                    m.min.as_nanos(),
+                    m.median.as_nanos(),
+                    m.mean.as_nanos()
+                )
+                .unwrap();
+            }
+        }
+    }
+    out
+}
+
+/// Reads medians from a previous [`report`] in CSV format, keyed by benchmark id.
+pub fn parse_baseline(csv: &str) -> Result<HashMap<String, Duration>, AlgoError> {
+    let mut baseline = HashMap::new();
+    for (idx, line) in csv.lines().enumerate().skip(1) {
+        let fields = csv_fields(line);
+        let [id, _, _, median, _] = &fields[..] else {
+            let reason = format!("line {}: expected 5 fields, got {}", idx + 1, fields.len());
+            return Err(AlgoError::InvalidInput(reason));
+        };
+        let median: u64 = median.parse().map_err(|_| {
+            AlgoError::InvalidInput(format!(
+                "line {}: median '{median}' is not an integer",
+                idx + 1
+            ))
+        })?;
+        baseline.insert(id.clone(), Duration::from_nanos(median));
+    }
+    Ok(baseline)
+}
+
+/// Fills in [`Measurement::baseline`] for every result that has one.
+pub fn apply_baseline(results: &mut [Measurement], baseline: &HashMap<String, Duration>) {
+    for m in results {
+        m.baseline = baseline.get(&m.id).copied();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// Advances by one millisecond every time it's read.
+    struct FakeClock(Cell<u64>);
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Duration {
+            self.0.set(self.0.get() + 1);
+            Duration::from_millis(self.0.get())
+        }
+    }
+
+    #[test]
+    fn runner_test() {
+        let calls = std::rc::Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let mut suites = [
+            Suite::new("math").bench("sum", || (0..10u64).sum::<u64>()),
+            Suite::new("graphs").bench("count", move || counter.set(counter.get() + 1)),
+        ];
+        assert_eq!(suites[1].ids().collect::<Vec<_>>(), ["graphs/count"]);
+
+        let options = Options {
+            filter: Some("graphs".to_owned()),
+            warmup: Duration::from_millis(3),
+            budget: Duration::from_millis(100),
+        };
+        let clock = FakeClock(Cell::new(0));
+        let results = run_with(&clock, &mut suites, &options);
+        assert_eq!(results.len(), 1);
+        let m = &results[0];
+        assert_eq!(m.id, "graphs/count");
+        assert_eq!(m.iterations, 50);
+        assert_eq!(
+            (m.min, m.median, m.mean),
+            (
+                Duration::from_millis(1),
+                Duration::from_millis(1),
+                Duration::from_millis(1)
+            )
+        );
+        // The fake clock allows two warmup calls, then one to estimate iterations.
+        assert_eq!(calls.get(), 2 + 1 + 50);
+    }
+
+    /// Never advances, like a coarse clock timing a very fast benchmark. Counts its reads.
+    struct StoppedClock(Cell<u32>);
+
+    impl Clock for StoppedClock {
+        fn now(&self) -> Duration {
+            self.0.set(self.0.get() + 1);
+            Duration::ZERO
+        }
+    }
+
+    #[test]
+    fn zero_measurement_test() {
+        assert_eq!(
+            calc_iterations(Duration::ZERO, Duration::from_secs(1)),
+            500_000_000
+        );
+        assert_eq!(
+            calc_iterations(Duration::from_nanos(1), Duration::from_secs(100)),
+            500_000_000
+        );
+
+        let mut suites = [Suite::new("math").bench("noop", || ())];
+        let options = Options {
+            filter: None,
+            warmup: Duration::ZERO,
+            budget: Duration::from_micros(1),
+        };
+        let clock = StoppedClock(Cell::new(0));
+        let results = run_with(&clock, &mut suites, &options);
+        assert_eq!(results[0].iterations, 5_000);
+        assert_eq!(results[0].mean, Duration::ZERO);
+        // Two reads per sample: the 5000 iterations are timed as 1000 batches of 5,
+        // plus the warmup and the estimate.
+        assert_eq!(clock.0.get(), 2 + 2 + 2 * MAX_SAMPLES);
+    }
+
+    #[test]
+    fn report_and_baseline_test() {
+        let mut results = vec![Measurement {
+            id: "math/fib".to_owned(),
+            iterations: 10,
+            min: Duration::from_nanos(90),
+            median: Duration::from_nanos(150),
+            mean: Duration::from_nanos(120),
+            baseline: None,
+        }];
+
+        let csv = report(&results, Format::Csv);
+        assert_eq!(
+            csv,
+            "id,iterations,min_ns,median_ns,mean_ns\nmath/fib,10,90,150,120\n"
+        );
+        let mut baseline = parse_baseline(&csv).unwrap();
+        baseline.insert("math/fib".to_owned(), Duration::from_nanos(200));
+        apply_baseline(&mut results, &baseline);
+        assert_eq!(results[0].change(), Some(-0.25));
+
+        let md = report(&results, Format::Markdown);
+        assert!(md.starts_with(
+            "| benchmark | iterations | min | median | mean | change |\n|---|---:|---:|---:|---:|---:|\n"
+        ));
+        assert!(md.ends_with("| math/fib | 10 | 90ns | 150ns | 120ns | -25.0% |\n"));
+        let table = report(&results, Format::Table);
+        assert!(table.contains("| math/fib  | 10         | 90ns | 150ns  | 120ns | -25.0% |\n"));
+        assert_eq!(
+            report(&results, Format::Json),
+            r#"[{"id":"math/fib","iterations":10,"min_ns":90,"median_ns":150,"mean_ns":120,"baseline_ns":200}]"#
+        );
+
+        assert_eq!("md".parse(), Ok(Format::Markdown));
+        assert_eq!("markdown".parse(), Ok(Format::Markdown));
+        assert_eq!("table".parse(), Ok(Format::Table));
+        assert!("xml".parse::<Format>().is_err());
+        assert!(parse_baseline("header\na,1").is_err());
+
+        results[0].id = r#"math/fib "10, 20""#.to_owned();
+        let csv = report(&results, Format::Csv);
+        assert!(csv.ends_with("\"math/fib \"\"10, 20\"\"\",10,90,150,120\n"));
+        let baseline = parse_baseline(&csv).unwrap();
+        assert_eq!(baseline[&results[0].id], Duration::from_nanos(150));
+
+        results[0].id = "math/a|b".to_owned();
+        assert!(report(&results, Format::Markdown).contains("| math/a\\|b | 10 |"));
+    }
+}
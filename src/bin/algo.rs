@@ -0,0 +1,285 @@
+use std::process::ExitCode;
+use std::{env, fs, io};
+
+use algo_examples::error::AlgoError;
+use algo_examples::formatting::render_table;
+use algo_examples::graphs::parse::parse_edge_list;
+use algo_examples::graphs::{as_borrowed, shortest_path_solver, NodeGraph, ShortestPath};
+use algo_examples::trace::Recorder;
+
+const USAGE: &str = "\
+Usage: algo <ALGORITHM> [OPTIONS] <GRAPH>
+
+Runs an algorithm on a graph read from a file ('-' for stdin).
+
+Algorithms:
+  dijkstra  Shortest path between two nodes (non-negative weights only)
+
+Options:
+  --from <NODE>      Start node
+  --to <NODE>        Finish node
+  --explain          Print every step the algorithm took
+  --html <FILE>      Also write an HTML page with the graph, the path and a replay of the steps
+  --format <FORMAT>  Input format: 'edges' (whitespace or comma separated `from to weight`
+                     lines, the default) or 'json' (guessed from a .json extension,
+                     needs a build with `--features json`)
+  -h, --help         Print help";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Edges,
+    Json,
+}
+
+const NO_JSON: &str = "JSON input needs the `json` feature, rebuild with `--features json`";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Args {
+    algorithm: String,
+    from: Option<String>,
+    to: Option<String>,
+    format: Option<Format>,
+    explain: bool,
+    html: Option<String>,
+    graph: Option<String>,
+}
+
+fn value(flag: &str, args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("'{flag}' needs a value"))
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--from" => parsed.from = Some(value(&arg, &mut args)?),
+            "--to" => parsed.to = Some(value(&arg, &mut args)?),
+            "--explain" => parsed.explain = true,
+            "--html" => parsed.html = Some(value(&arg, &mut args)?),
+            "--format" => {
+                parsed.format = match value(&arg, &mut args)?.as_str() {
+                    "edges" | "csv" => Some(Format::Edges),
+                    "json" if cfg!(feature = "json") => Some(Format::Json),
+                    "json" => return Err(NO_JSON.to_owned()),
+                    other => return Err(format!("unknown format '{other}'")),
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{flag}'")),
+            _ if parsed.algorithm.is_empty() => parsed.algorithm = arg,
+            _ if parsed.graph.is_none() => parsed.graph = Some(arg),
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+
+    if parsed.algorithm.is_empty() {
+        return Err("no algorithm given".to_owned());
+    }
+    Ok(Some(parsed))
+}
+
+fn read_graph(path: &str, format: Option<Format>) -> Result<NodeGraph<String, i32>, String> {
+    let input = if path == "-" {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    }
+    .map_err(|e| format!("can't read '{path}': {e}"))?;
+
+    let format = format.unwrap_or(if path.ends_with(".json") {
+        Format::Json
+    } else {
+        Format::Edges
+    });
+    let graph = match format {
+        Format::Edges => parse_edge_list(&input),
+        #[cfg(feature = "json")]
+        Format::Json => algo_examples::graphs::parse::parse_json(&input),
+        #[cfg(not(feature = "json"))]
+        Format::Json => Err(AlgoError::invalid_input(NO_JSON)),
+    };
+    graph.map_err(|e| format!("can't parse '{path}': {e}"))
+}
+
+#[cfg(feature = "html")]
+fn write_html(
+    path: &str,
+    title: &str,
+    graph: &NodeGraph<&String, i32>,
+    nodes: &[&String],
+    recorder: &Recorder,
+) -> Result<(), AlgoError> {
+    let page = algo_examples::graphs::html::render_html(title, graph, nodes, recorder.events());
+    fs::write(path, page).map_err(|e| AlgoError::InvalidInput(format!("can't write '{path}': {e}")))
+}
+
+#[cfg(not(feature = "html"))]
+fn write_html(
+    _: &str,
+    _: &str,
+    _: &NodeGraph<&String, i32>,
+    _: &[&String],
+    _: &Recorder,
+) -> Result<(), AlgoError> {
+    Err(AlgoError::invalid_input(
+        "HTML export needs the `html` feature",
+    ))
+}
+
+fn shortest_path(
+    solver: &dyn ShortestPath<String>,
+    graph: &NodeGraph<String, i32>,
+    from: &str,
+    to: &str,
+    explain: bool,
+    html: Option<&str>,
+) -> Result<String, AlgoError> {
+    let graph = as_borrowed(graph);
+    let (from, to) = (&from.to_owned(), &to.to_owned());
+    let mut recorder = Recorder::new();
+    let path = solver.find_traced(&graph, from, to, &mut recorder)?;
+
+    let mut cost = 0;
+    let mut rows = Vec::with_capacity(path.nodes.len());
+    for (step, node) in path.nodes.iter().enumerate() {
+        if step > 0 {
+            cost += graph[path.nodes[step - 1]][node];
+        }
+        rows.push(vec![step.to_string(), node.to_string(), cost.to_string()]);
+    }
+
+    let table = render_table(&["step", "node", "cost"], &rows);
+    let summary = format!("Shortest path from '{from}' to '{to}' costs {}", path.cost);
+    if let Some(html) = html {
+        let title = format!("{} from '{from}' to '{to}'", solver.name());
+        write_html(html, &title, &graph, &path.nodes, &recorder)?;
+    }
+    if explain {
+        Ok(format!("{}\n{table}\n{summary}", recorder.explain()))
+    } else {
+        Ok(format!("{table}\n{summary}"))
+    }
+}
+
+fn run(args: Args) -> Result<String, String> {
+    let graph_path = args.graph.as_deref().ok_or("no graph file given")?;
+    let solver = shortest_path_solver(&args.algorithm)
+        .ok_or_else(|| format!("unknown algorithm '{}'", args.algorithm))?;
+    let needs = |flag| format!("{} needs '{flag}'", solver.name());
+    let from = args.from.as_deref().ok_or_else(|| needs("--from"))?;
+    let to = args.to.as_deref().ok_or_else(|| needs("--to"))?;
+    let graph = read_graph(graph_path, args.format)?;
+    shortest_path(
+        &*solver,
+        &graph,
+        from,
+        to,
+        args.explain,
+        args.html.as_deref(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args) {
+        Ok(output) => {
+            println!("{output}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Args>, String> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn parse_args_test() {
+        let args = parse(&["dijkstra", "--from", "a", "--to", "b", "--explain", "g.txt"]);
+        let expected = Args {
+            algorithm: "dijkstra".to_owned(),
+            from: Some("a".to_owned()),
+            to: Some("b".to_owned()),
+            explain: true,
+            graph: Some("g.txt".to_owned()),
+            ..Args::default()
+        };
+        assert_eq!(args, Ok(Some(expected)));
+        assert_eq!(parse(&["dijkstra", "--help"]), Ok(None));
+        let args = parse(&["dijkstra", "--format", "csv", "-"])
+            .unwrap()
+            .unwrap();
+        assert_eq!(args.format, Some(Format::Edges));
+    }
+
+    #[test]
+    fn parse_args_errors_test() {
+        assert_eq!(parse(&[]), Err("no algorithm given".to_owned()));
+        assert_eq!(
+            parse(&["dijkstra", "--verbose"]),
+            Err("unknown option '--verbose'".to_owned())
+        );
+        assert_eq!(
+            parse(&["dijkstra", "g.txt", "--from"]),
+            Err("'--from' needs a value".to_owned())
+        );
+        assert_eq!(
+            parse(&["dijkstra", "--format", "xml"]),
+            Err("unknown format 'xml'".to_owned())
+        );
+        assert_eq!(
+            parse(&["dijkstra", "a.txt", "b.txt"]),
+            Err("unexpected argument 'b.txt'".to_owned())
+        );
+        let json = parse(&["dijkstra", "--format", "json"]);
+        if cfg!(feature = "json") {
+            assert!(json.is_ok());
+        } else {
+            assert_eq!(json, Err(NO_JSON.to_owned()));
+        }
+    }
+
+    #[test]
+    fn run_errors_test() {
+        let args = |algorithm: &str| Args {
+            algorithm: algorithm.to_owned(),
+            graph: Some("missing.txt".to_owned()),
+            ..Args::default()
+        };
+        assert_eq!(
+            run(args("bellman-ford")),
+            Err("unknown algorithm 'bellman-ford'".to_owned())
+        );
+        assert_eq!(
+            run(args("dijkstra")),
+            Err("dijkstra needs '--from'".to_owned())
+        );
+        assert_eq!(
+            run(Args {
+                graph: None,
+                ..args("dijkstra")
+            }),
+            Err("no graph file given".to_owned())
+        );
+    }
+}
@@ -0,0 +1,205 @@
+use std::process::ExitCode;
+use std::time::Duration;
+use std::{env, fs};
+
+use algo_examples::benchmarking::runner::{self, Format, Options, Suite};
+use algo_examples::error::AlgoError;
+
+const USAGE: &str = "\
+Usage: bench [OPTIONS]
+
+Runs the registered benchmark suites.
+
+Options:
+  --filter <TEXT>      Only run benchmarks whose `suite/bench` id contains TEXT
+  --warmup <TIME>      Warm-up time per benchmark, e.g. '200ms' (default) or '1s'
+  --budget <TIME>      Approximate measuring time per benchmark (default '1s')
+  --baseline <FILE>    Compare medians against a previous run saved with '--format csv'
+  --format <FORMAT>    'markdown' (default), 'table', 'json' or 'csv'
+  --list               Print benchmark ids instead of running them
+  -h, --help           Print help";
+
+#[cfg(feature = "graphs")]
+fn graphs_suite() -> Suite {
+    use algo_examples::graphs::dijkstra::dejkstras_alg;
+    use algo_examples::graphs::NodeGraph;
+    use algo_examples::rng::Rng;
+    use algo_examples::testing::generators::graph;
+
+    // Node ids live in a static, so the closure can own a graph of references to them.
+    static NODES: [u16; 200] = {
+        let mut nodes = [0; 200];
+        let mut i = 0;
+        while i < nodes.len() {
+            nodes[i] = i as u16;
+            i += 1;
+        }
+        nodes
+    };
+    let id = |name: &str| &NODES[name[1..].parse::<usize>().unwrap()];
+    let generated = graph(&mut Rng::new(1), NODES.len(), 0.05, 1..=100);
+    let graph: NodeGraph<&u16, i32> = generated
+        .iter()
+        .map(|(from, neighbors)| {
+            let neighbors = neighbors.iter().map(|(to, &w)| (id(to), w)).collect();
+            (id(from), neighbors)
+        })
+        .collect();
+    Suite::new("graphs").bench("dijkstra_200", move || {
+        dejkstras_alg(&graph, &NODES[0], &NODES[199])
+    })
+}
+
+#[cfg(feature = "math")]
+fn math_suite() -> Suite {
+    use algo_examples::math::fibonacci::{fibonacci, fibonacci_memo};
+    use algo_examples::math::ntt::{multiply_polynomials, multiply_polynomials_naive};
+    use algo_examples::rng::Rng;
+    use algo_examples::testing::generators::big_uint;
+
+    let mut rng = Rng::new(2);
+    let (a, b) = (big_uint(&mut rng, 256), big_uint(&mut rng, 256));
+    let (c, d) = (a.clone(), b.clone());
+    let poly: Vec<u64> = (0..1_024).map(|_| rng.gen_range(0..1_000)).collect();
+    let poly2 = poly.clone();
+
+    Suite::new("math")
+        .bench("big_uint_schoolbook_256", move || a.mul_schoolbook(&b))
+        .bench("big_uint_karatsuba_256", move || c.mul_karatsuba(&d))
+        .bench("poly_naive_1024", move || {
+            multiply_polynomials_naive(&poly, &poly)
+        })
+        .bench("poly_ntt_1024", move || {
+            multiply_polynomials(&poly2, &poly2)
+        })
+        .bench("fibonacci_memo_90", || fibonacci_memo(90))
+        .bench("fibonacci_90", || fibonacci(90))
+}
+
+#[cfg(feature = "ml")]
+fn ml_suite() -> Suite {
+    use algo_examples::ml::kmeans::kmeans;
+    use algo_examples::ml::knn::KnnClassifier;
+    use algo_examples::rng::Rng;
+
+    let mut rng = Rng::new(3);
+    let points: Vec<Vec<f64>> = (0..1_000)
+        .map(|_| vec![rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)])
+        .collect();
+    let mut knn = KnnClassifier::new(5);
+    knn.fit(points.clone(), (0..1_000).map(|i| i % 3).collect());
+
+    Suite::new("ml")
+        .bench("knn_predict_1000", move || knn.predict(&[50.0, 50.0]))
+        .bench("kmeans_1000", move || {
+            kmeans(&points, 3, 20, &mut Rng::new(4))
+        })
+}
+
+/// Every benchmark suite, one per enabled algorithm family.
+fn suites() -> Vec<Suite> {
+    vec![
+        #[cfg(feature = "graphs")]
+        graphs_suite(),
+        #[cfg(feature = "math")]
+        math_suite(),
+        #[cfg(feature = "ml")]
+        ml_suite(),
+    ]
+}
+
+#[derive(Debug, Default)]
+struct Args {
+    options: Options,
+    baseline: Option<String>,
+    format: Format,
+    list: bool,
+}
+
+fn value(flag: &str, args: &mut impl Iterator<Item = String>) -> Result<String, String> {
+    args.next().ok_or_else(|| format!("'{flag}' needs a value"))
+}
+
+fn parse_duration(s: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid time '{s}', expected e.g. '500ms' or '2s'");
+    let (number, unit) = match s.strip_suffix("ms") {
+        Some(ms) => (ms, Duration::from_millis(1)),
+        None => (
+            s.strip_suffix('s').ok_or_else(invalid)?,
+            Duration::from_secs(1),
+        ),
+    };
+    let number: u32 = number.parse().map_err(|_| invalid())?;
+    Ok(unit * number)
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut parsed = Args::default();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--filter" => parsed.options.filter = Some(value(&arg, &mut args)?),
+            "--warmup" => parsed.options.warmup = parse_duration(&value(&arg, &mut args)?)?,
+            "--budget" => parsed.options.budget = parse_duration(&value(&arg, &mut args)?)?,
+            "--baseline" => parsed.baseline = Some(value(&arg, &mut args)?),
+            "--format" => {
+                parsed.format = value(&arg, &mut args)?
+                    .parse()
+                    .map_err(|e: AlgoError| e.to_string())?
+            }
+            "--list" => parsed.list = true,
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+    Ok(Some(parsed))
+}
+
+fn run(args: Args) -> Result<String, String> {
+    let mut suites = suites();
+    if args.list {
+        let ids: Vec<String> = suites.iter().flat_map(Suite::ids).collect();
+        return Ok(ids.join("\n"));
+    }
+
+    let baseline = match &args.baseline {
+        Some(path) => {
+            let csv = fs::read_to_string(path).map_err(|e| format!("can't read '{path}': {e}"))?;
+            Some(runner::parse_baseline(&csv).map_err(|e| format!("can't parse '{path}': {e}"))?)
+        }
+        None => None,
+    };
+
+    let mut results = runner::run(&mut suites, &args.options);
+    if results.is_empty() {
+        return Err("no benchmarks match the filter".to_owned());
+    }
+    if let Some(baseline) = baseline {
+        runner::apply_baseline(&mut results, &baseline);
+    }
+    Ok(runner::report(&results, args.format))
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(args) {
+        Ok(output) => {
+            println!("{}", output.trim_end());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
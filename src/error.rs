@@ -0,0 +1,79 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Reasons an algorithm can't produce an answer.
+///
+/// Modules with richer errors of their own (e.g. `math`'s `ModInvError`) convert into
+/// [`InvalidInput`](AlgoError::InvalidInput) via `From`, so `?` works across APIs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AlgoError {
+    /// A node is referenced but isn't present in the graph.
+    MissingNode(String),
+    /// There's no path between two nodes.
+    Unreachable { from: String, to: String },
+    /// An edge has a negative weight, which the algorithm doesn't support.
+    NegativeWeight {
+        from: String,
+        to: String,
+        weight: i64,
+    },
+    /// Input violates the algorithm's preconditions.
+    InvalidInput(String),
+}
+
+impl AlgoError {
+    pub fn invalid_input(reason: impl Into<String>) -> Self {
+        Self::InvalidInput(reason.into())
+    }
+}
+
+impl fmt::Display for AlgoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingNode(node) => write!(f, "node '{node}' is not in the graph"),
+            Self::Unreachable { from, to } => write!(f, "'{to}' is unreachable from '{from}'"),
+            Self::NegativeWeight { from, to, weight } => {
+                write!(f, "edge '{from}' -> '{to}' has negative weight {weight}")
+            }
+            Self::InvalidInput(reason) => write!(f, "invalid input: {reason}"),
+        }
+    }
+}
+
+impl core::error::Error for AlgoError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn algo_error_display_test() {
+        assert_eq!(
+            AlgoError::MissingNode("a".into()).to_string(),
+            "node 'a' is not in the graph"
+        );
+        let err = AlgoError::NegativeWeight {
+            from: "c".into(),
+            to: "b".into(),
+            weight: -1,
+        };
+        assert_eq!(err.to_string(), "edge 'c' -> 'b' has negative weight -1");
+        assert_eq!(
+            AlgoError::invalid_input("k must be positive").to_string(),
+            "invalid input: k must be positive"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn algo_error_serde_test() {
+        let err = AlgoError::Unreachable {
+            from: "start".into(),
+            to: "fin".into(),
+        };
+        let json = serde_json::to_string(&err).unwrap();
+        assert_eq!(json, r#"{"Unreachable":{"from":"start","to":"fin"}}"#);
+        assert_eq!(serde_json::from_str::<AlgoError>(&json).unwrap(), err);
+    }
+}
@@ -0,0 +1,101 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt::Display;
+use core::hash::Hash;
+
+use crate::collections::HashMap;
+use crate::error::AlgoError;
+use crate::trace::{NoTrace, Tracer};
+
+pub mod dijkstra;
+#[cfg(feature = "html")]
+pub mod html;
+pub mod parse;
+
+/// Adjacency map: every node maps to its neighbors and the weights of edges to them.
+pub type NodeGraph<K, V> = HashMap<K, HashMap<K, V>>;
+
+/// Sequence of nodes from start to finish together with its total cost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Path<N> {
+    pub nodes: Vec<N>,
+    pub cost: i32,
+}
+
+/// Borrows an owned graph, so it can be passed to algorithms expecting `NodeGraph<&K, V>`.
+pub fn as_borrowed<K, V>(graph: &NodeGraph<K, V>) -> NodeGraph<&K, V>
+where
+    K: Eq + Hash,
+    V: Copy,
+{
+    graph
+        .iter()
+        .map(|(node, neighbors)| (node, neighbors.iter().map(|(n, &w)| (n, w)).collect()))
+        .collect()
+}
+
+/// Algorithm finding the cheapest path between two nodes, selectable at runtime.
+pub trait ShortestPath<K: ?Sized> {
+    /// Name used to pick the algorithm, e.g. on the command line.
+    fn name(&self) -> &'static str;
+
+    /// Shortest path from `start` to `finish`, reporting steps to `tracer`.
+    fn find_traced<'a>(
+        &self,
+        graph: &NodeGraph<&'a K, i32>,
+        start: &'a K,
+        finish: &'a K,
+        tracer: &mut dyn Tracer,
+    ) -> Result<Path<&'a K>, AlgoError>;
+
+    fn find<'a>(
+        &self,
+        graph: &NodeGraph<&'a K, i32>,
+        start: &'a K,
+        finish: &'a K,
+    ) -> Result<Path<&'a K>, AlgoError> {
+        self.find_traced(graph, start, finish, &mut NoTrace)
+    }
+}
+
+/// Every shortest path algorithm in the crate.
+pub fn shortest_path_solvers<K>() -> Vec<Box<dyn ShortestPath<K>>>
+where
+    K: Eq + Hash + Display + ?Sized,
+{
+    vec![Box::new(dijkstra::Dijkstra)]
+}
+
+/// Shortest path algorithm called `name`, if there is one.
+pub fn shortest_path_solver<K>(name: &str) -> Option<Box<dyn ShortestPath<K>>>
+where
+    K: Eq + Hash + Display + ?Sized,
+{
+    shortest_path_solvers()
+        .into_iter()
+        .find(|s| s.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shorthands::new_h_map;
+
+    #[test]
+    fn shortest_path_solvers_test() {
+        let (start, finish) = ("start", "finish");
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(finish, 3)]));
+        graph.insert(finish, HashMap::new());
+
+        for solver in shortest_path_solvers::<str>() {
+            let path = solver.find(&graph, start, finish).unwrap();
+            assert_eq!(path.nodes, [start, finish], "{}", solver.name());
+            assert_eq!(path.cost, 3, "{}", solver.name());
+        }
+        assert!(shortest_path_solver::<str>("dijkstra").is_some());
+        assert!(shortest_path_solver::<str>("astar").is_none());
+    }
+}
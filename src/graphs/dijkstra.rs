@@ -0,0 +1,497 @@
+use alloc::string::ToString;
+use alloc::{format, vec};
+use core::fmt::Display;
+use core::hash::Hash;
+
+use super::{NodeGraph, Path, ShortestPath};
+use crate::collections::{HashMap, HashSet};
+use crate::error::AlgoError;
+use crate::trace::{Event, NoTrace, Tracer};
+
+fn find_lowest_cost_node<'a, K: Eq + Hash + ?Sized>(
+    costs: &HashMap<&'a K, i32>,
+    processed: &HashSet<&K>,
+) -> Option<&'a K> {
+    costs
+        .iter()
+        .filter(|(node, _)| !processed.contains(*node))
+        .min_by_key(|(_, &cost)| cost)
+        .map(|(&node, _)| node)
+}
+
+/// What a single [`DijkstraState::step`] did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome<N> {
+    /// `node` was processed, `cost` is the final cost of reaching it.
+    Visited { node: N, cost: i32 },
+    /// Every reachable node has been processed.
+    Done,
+}
+
+/// Dijkstra's algorithm that runs one node at a time, so it can be paused and inspected.
+///
+/// If a step fails, the state is left half-updated and shouldn't be stepped further.
+#[derive(Debug, Clone)]
+pub struct DijkstraState<'g, 'a, K: ?Sized> {
+    graph: &'g NodeGraph<&'a K, i32>,
+    start: &'a K,
+    /// Only nodes that were reached, so every cost here is a real one.
+    costs: HashMap<&'a K, i32>,
+    parents: HashMap<&'a K, &'a K>,
+    processed: HashSet<&'a K>,
+}
+
+impl<'g, 'a, K: Eq + Hash + Display + ?Sized> DijkstraState<'g, 'a, K> {
+    pub fn new(graph: &'g NodeGraph<&'a K, i32>, start: &'a K) -> Self {
+        Self {
+            graph,
+            start,
+            costs: HashMap::from([(start, 0)]),
+            parents: HashMap::new(),
+            processed: HashSet::new(),
+        }
+    }
+
+    /// Processes the cheapest node of the frontier.
+    pub fn step(&mut self) -> Result<StepOutcome<&'a K>, AlgoError> {
+        self.step_traced(&mut NoTrace)
+    }
+
+    /// Same as [`step`](Self::step), reporting the visited node and considered edges to `tracer`.
+    pub fn step_traced<T: Tracer + ?Sized>(
+        &mut self,
+        tracer: &mut T,
+    ) -> Result<StepOutcome<&'a K>, AlgoError> {
+        let Some(node) = find_lowest_cost_node(&self.costs, &self.processed) else {
+            return Ok(StepOutcome::Done);
+        };
+        let cost = self.costs[node];
+        let neighbors = self
+            .graph
+            .get(node)
+            .ok_or_else(|| AlgoError::MissingNode(node.to_string()))?;
+        #[cfg(feature = "tracing")]
+        tracing::trace!(%node, cost, "visit");
+        let traced = tracer.enabled();
+        if traced {
+            tracer.record(Event::Visit {
+                node: node.to_string(),
+                cost: cost.into(),
+            });
+        }
+
+        for (&n, &weight) in neighbors {
+            if weight < 0 {
+                return Err(AlgoError::NegativeWeight {
+                    from: node.to_string(),
+                    to: n.to_string(),
+                    weight: weight.into(),
+                });
+            }
+            let new_cost = cost.checked_add(weight).ok_or_else(|| {
+                AlgoError::InvalidInput(format!("cost of reaching {n} via {node} overflows i32"))
+            })?;
+            let old_cost = self.costs.get(n).copied();
+            if old_cost.is_none_or(|old_cost| new_cost < old_cost) {
+                self.costs.insert(n, new_cost);
+                self.parents.insert(n, node);
+                #[cfg(feature = "tracing")]
+                tracing::trace!(from = %node, to = %n, new_cost, "relax");
+                if traced {
+                    tracer.record(Event::Relax {
+                        from: node.to_string(),
+                        to: n.to_string(),
+                        old_cost: old_cost.map(i64::from),
+                        new_cost: new_cost.into(),
+                    });
+                }
+            } else if let Some(best) = old_cost.filter(|_| traced) {
+                tracer.record(Event::Keep {
+                    from: node.to_string(),
+                    to: n.to_string(),
+                    cost: new_cost.into(),
+                    best: best.into(),
+                });
+            }
+        }
+
+        self.processed.insert(node);
+        Ok(StepOutcome::Visited { node, cost })
+    }
+
+    /// Steps until every reachable node is processed.
+    pub fn run_traced<T: Tracer + ?Sized>(&mut self, tracer: &mut T) -> Result<(), AlgoError> {
+        while let StepOutcome::Visited { .. } = self.step_traced(tracer)? {}
+        Ok(())
+    }
+
+    /// Whether there's nothing left to process.
+    pub fn is_done(&self) -> bool {
+        find_lowest_cost_node(&self.costs, &self.processed).is_none()
+    }
+
+    /// Reached, but not yet processed nodes with their best known costs.
+    pub fn frontier(&self) -> impl Iterator<Item = (&'a K, i32)> + '_ {
+        self.costs
+            .iter()
+            .filter(|(node, _)| !self.processed.contains(*node))
+            .map(|(&node, &cost)| (node, cost))
+    }
+
+    /// Best known cost of reaching `node`. Final once `node` is processed.
+    pub fn cost(&self, node: &K) -> Option<i32> {
+        self.costs.get(node).copied()
+    }
+
+    pub fn is_processed(&self, node: &K) -> bool {
+        self.processed.contains(node)
+    }
+
+    /// Best known path from the start to `finish`, or `None` if it wasn't reached yet.
+    pub fn path_to(&self, finish: &'a K) -> Option<Path<&'a K>> {
+        let cost = self.cost(finish)?;
+        let mut nodes = vec![finish];
+        while let Some(&parent) = self.parents.get(nodes[nodes.len() - 1]) {
+            nodes.push(parent);
+        }
+        if nodes[nodes.len() - 1] != self.start {
+            return None;
+        }
+        nodes.reverse();
+        Some(Path { nodes, cost })
+    }
+}
+
+/// Dejkstra's algorithm implementation used to find the
+/// shortest path in a weighted graph.
+///
+/// [!!] Cannot be used with negative weights. [!!]
+/// Such edges are rejected with `AlgoError::NegativeWeight` as soon as they're reached.
+///
+/// Never panics, whatever the graph: path costs that don't fit into `i32` are reported
+/// as `AlgoError::InvalidInput`.
+pub fn dejkstras_alg<K: Eq + Hash + Display + ?Sized>(
+    graph: &NodeGraph<&K, i32>,
+    start: &K,
+    finish: &K,
+) -> Result<i32, AlgoError> {
+    dejkstras_path(graph, start, finish).map(|path| path.cost)
+}
+
+/// Same as [`dejkstras_alg`], but also returns the nodes along the shortest path.
+pub fn dejkstras_path<'a, K: Eq + Hash + Display + ?Sized>(
+    graph: &NodeGraph<&'a K, i32>,
+    start: &'a K,
+    finish: &'a K,
+) -> Result<Path<&'a K>, AlgoError> {
+    dejkstras_path_traced(graph, start, finish, &mut NoTrace)
+}
+
+/// Same as [`dejkstras_path`], reporting every visited node and considered edge to `tracer`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(%start, %finish, nodes = graph.len()))
+)]
+pub fn dejkstras_path_traced<'a, K, T>(
+    graph: &NodeGraph<&'a K, i32>,
+    start: &'a K,
+    finish: &'a K,
+    tracer: &mut T,
+) -> Result<Path<&'a K>, AlgoError>
+where
+    K: Eq + Hash + Display + ?Sized,
+    T: Tracer + ?Sized,
+{
+    if !graph.contains_key(finish) {
+        return Err(AlgoError::MissingNode(finish.to_string()));
+    }
+    let mut state = DijkstraState::new(graph, start);
+    state.run_traced(tracer)?;
+    state.path_to(finish).ok_or_else(|| AlgoError::Unreachable {
+        from: start.to_string(),
+        to: finish.to_string(),
+    })
+}
+
+/// [`ShortestPath`] implementation backed by [`dejkstras_path_traced`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Dijkstra;
+
+impl<K: Eq + Hash + Display + ?Sized> ShortestPath<K> for Dijkstra {
+    fn name(&self) -> &'static str {
+        "dijkstra"
+    }
+
+    fn find_traced<'a>(
+        &self,
+        graph: &NodeGraph<&'a K, i32>,
+        start: &'a K,
+        finish: &'a K,
+        tracer: &mut dyn Tracer,
+    ) -> Result<Path<&'a K>, AlgoError> {
+        dejkstras_path_traced(graph, start, finish, tracer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shorthands::new_h_map;
+
+    #[test]
+    fn dejkstras_algorithm_test() {
+        let (start, finish, a, b, c, d) = ("start", "finish", "a", "b", "c", "d");
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 6), (b, 2)]));
+        graph.insert(b, new_h_map([(a, 3), (finish, 5)]));
+        graph.insert(a, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+        let res = dejkstras_alg(&graph, start, finish);
+        assert_eq!(res, Ok(6));
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 5), (b, 2)]));
+        graph.insert(a, new_h_map([(c, 4), (d, 2)]));
+        graph.insert(b, new_h_map([(a, 8), (d, 7)]));
+        graph.insert(c, new_h_map([(finish, 3), (d, 6)]));
+        graph.insert(d, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+        let res = dejkstras_alg(&graph, start, finish);
+        assert!(res.unwrap() <= 8);
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 10)]));
+        graph.insert(a, new_h_map([(c, 20)]));
+        graph.insert(b, new_h_map([(a, 1)]));
+        graph.insert(c, new_h_map([(b, 1), (finish, 30)]));
+        graph.insert(finish, HashMap::new());
+        let res = dejkstras_alg(&graph, start, finish);
+        assert!(res.unwrap() <= 60);
+
+        // Negative weights used to get "outweighted" in small examples like this one,
+        // but the answer can't be trusted in general, so now they're rejected.
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 2), (b, 2)]));
+        graph.insert(a, new_h_map([(b, 2)]));
+        graph.insert(b, new_h_map([(c, 2), (finish, 2)]));
+        graph.insert(c, new_h_map([(b, -1), (finish, 2)]));
+        graph.insert(finish, HashMap::new());
+        let res = dejkstras_alg(&graph, start, finish);
+        let err = AlgoError::NegativeWeight {
+            from: c.into(),
+            to: b.into(),
+            weight: -1,
+        };
+        assert_eq!(res, Err(err));
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, i32::MAX - 1)]));
+        graph.insert(a, new_h_map([(finish, 2)]));
+        graph.insert(finish, HashMap::new());
+        let err = AlgoError::invalid_input("cost of reaching finish via a overflows i32");
+        assert_eq!(dejkstras_alg(&graph, start, finish), Err(err));
+
+        // `i32::MAX` is a valid cost, not a marker for unreached nodes.
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(finish, i32::MAX)]));
+        graph.insert(finish, HashMap::new());
+        let path = dejkstras_path(&graph, start, finish).unwrap();
+        assert_eq!(path.nodes, [start, finish]);
+        assert_eq!(path.cost, i32::MAX);
+    }
+
+    #[test]
+    fn dejkstras_algorithm_errors_test() {
+        let (start, finish, a, b) = ("start", "finish", "a", "b");
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 1)]));
+        graph.insert(a, HashMap::new());
+        graph.insert(finish, new_h_map([(start, 1)]));
+        let unreachable = AlgoError::Unreachable {
+            from: start.into(),
+            to: finish.into(),
+        };
+        assert_eq!(dejkstras_alg(&graph, start, finish), Err(unreachable));
+        assert_eq!(dejkstras_alg(&graph, start, start), Ok(0));
+        assert_eq!(
+            dejkstras_alg(&graph, start, b),
+            Err(AlgoError::MissingNode(b.into()))
+        );
+
+        // `b` is a neighbor of `start`, but has no entry of its own.
+        graph.insert(start, new_h_map([(b, 1)]));
+        assert_eq!(
+            dejkstras_alg(&graph, start, finish),
+            Err(AlgoError::MissingNode(b.into()))
+        );
+    }
+
+    #[test]
+    fn dejkstras_path_test() {
+        let (start, finish, a, b) = ("start", "finish", "a", "b");
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 6), (b, 2)]));
+        graph.insert(b, new_h_map([(a, 3), (finish, 5)]));
+        graph.insert(a, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+
+        let path = dejkstras_path(&graph, start, finish).unwrap();
+        assert_eq!(path.nodes, [start, b, a, finish]);
+        assert_eq!(path.cost, 6);
+
+        let path = dejkstras_path(&graph, start, start).unwrap();
+        assert_eq!(path.nodes, [start]);
+        assert_eq!(path.cost, 0);
+    }
+
+    #[test]
+    fn dejkstras_path_traced_test() {
+        use crate::trace::Recorder;
+
+        let (start, finish, a) = ("start", "finish", "a");
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 1), (finish, 5)]));
+        graph.insert(a, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+
+        let mut recorder = Recorder::new();
+        let path = dejkstras_path_traced(&graph, start, finish, &mut recorder).unwrap();
+        assert_eq!(path.cost, 2);
+
+        let explanation = recorder.explain();
+        assert!(explanation.starts_with("1. visited start, final cost 0\n"));
+        assert!(explanation.contains("relaxed edge a→finish, cost 5→2"));
+        assert!(explanation.contains("visited finish, final cost 2"));
+        let visits = recorder
+            .events()
+            .iter()
+            .filter(|e| matches!(e, Event::Visit { .. }));
+        assert_eq!(visits.count(), 3);
+    }
+
+    /// Inputs from `fuzz/corpus/dijkstra/regression-*`, checked the way the fuzz target does.
+    #[test]
+    fn dejkstras_fuzz_regressions_test() {
+        let edges: &[&[(u8, u8, i32)]] = &[&[(0, 1, i32::MAX)]];
+        for &edges in edges {
+            let mut graph: NodeGraph<&u8, i32> = HashMap::new();
+            for (from, to, weight) in edges {
+                graph.entry(to).or_default();
+                graph.entry(from).or_default().insert(to, *weight);
+            }
+
+            let path = dejkstras_path(&graph, &0, &1).unwrap();
+            assert_eq!(path.nodes.first(), Some(&&0));
+            assert_eq!(path.nodes.last(), Some(&&1));
+            let cost: i64 = path
+                .nodes
+                .windows(2)
+                .map(|w| i64::from(graph[w[0]][w[1]]))
+                .sum();
+            assert_eq!(cost, i64::from(path.cost));
+        }
+    }
+
+    #[test]
+    fn dijkstra_state_test() {
+        let (start, finish, a, b) = ("start", "finish", "a", "b");
+
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 6), (b, 2)]));
+        graph.insert(b, new_h_map([(a, 3), (finish, 5)]));
+        graph.insert(a, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+
+        let mut state = DijkstraState::new(&graph, start);
+        assert_eq!(state.frontier().collect::<Vec<_>>(), [(start, 0)]);
+        assert_eq!(state.path_to(finish), None);
+
+        let visited = StepOutcome::Visited {
+            node: start,
+            cost: 0,
+        };
+        assert_eq!(state.step(), Ok(visited));
+        assert!(state.is_processed(start));
+        let mut frontier: Vec<_> = state.frontier().collect();
+        frontier.sort();
+        assert_eq!(frontier, [(a, 6), (b, 2)]);
+
+        // Paused halfway, `a` is only known through `start`.
+        assert_eq!(state.step(), Ok(StepOutcome::Visited { node: b, cost: 2 }));
+        assert_eq!(state.path_to(a).unwrap().nodes, [start, b, a]);
+        assert_eq!(state.cost(finish), Some(7));
+        assert!(!state.is_done());
+
+        assert_eq!(state.step(), Ok(StepOutcome::Visited { node: a, cost: 5 }));
+        let visited = StepOutcome::Visited {
+            node: finish,
+            cost: 6,
+        };
+        assert_eq!(state.step(), Ok(visited));
+        assert!(state.is_done());
+        assert_eq!(state.step(), Ok(StepOutcome::Done));
+        assert_eq!(state.path_to(finish).unwrap().nodes, [start, b, a, finish]);
+
+        // Unreached nodes are neither in the frontier nor have a path, even next to
+        // an edge whose weight is `i32::MAX`.
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, i32::MAX)]));
+        graph.insert(a, HashMap::new());
+        graph.insert(b, new_h_map([(a, 1)]));
+        let mut state = DijkstraState::new(&graph, start);
+        state.step().unwrap();
+        assert_eq!(state.frontier().collect::<Vec<_>>(), [(a, i32::MAX)]);
+        assert_eq!(state.path_to(b), None);
+        state.run_traced(&mut NoTrace).unwrap();
+        assert_eq!(state.path_to(a).unwrap().nodes, [start, a]);
+        assert_eq!(state.cost(b), None);
+    }
+
+    #[cfg(all(feature = "tracing", feature = "std"))]
+    #[test]
+    fn dejkstras_tracing_test() {
+        use std::sync::{Arc, Mutex};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+
+        /// Remembers names of spans and the number of events.
+        #[derive(Default)]
+        struct Collector(Mutex<(Vec<&'static str>, usize)>);
+
+        impl tracing::Subscriber for Collector {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut seen = self.0.lock().unwrap();
+                seen.0.push(span.metadata().name());
+                Id::from_u64(seen.0.len() as u64)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {
+                self.0.lock().unwrap().1 += 1;
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let (start, finish, a) = ("start", "finish", "a");
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 1), (finish, 5)]));
+        graph.insert(a, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+
+        let collector = Arc::new(Collector::default());
+        tracing::subscriber::with_default(collector.clone(), || {
+            assert_eq!(dejkstras_alg(&graph, start, finish), Ok(2));
+        });
+        let (spans, events) = &*collector.0.lock().unwrap();
+        assert_eq!(spans, &["dejkstras_path_traced"]);
+        // 3 visits and 3 relaxations.
+        assert_eq!(*events, 6);
+    }
+}
@@ -0,0 +1,230 @@
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::TAU;
+use std::fmt::{Display, Write};
+use std::hash::Hash;
+
+use super::NodeGraph;
+use crate::formatting::escape_json;
+use crate::trace::Event;
+
+const SIZE: f64 = 600.0;
+const NODE_RADIUS: f64 = 22.0;
+
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em; }
+svg { border: 1px solid #ccc; }
+line { stroke: #999; stroke-width: 2; }
+line.path { stroke: #e67e22; stroke-width: 4; }
+line.relaxed { stroke: #27ae60; stroke-width: 4; }
+circle { fill: #ecf0f1; stroke: #34495e; stroke-width: 2; }
+circle.path { stroke: #e67e22; stroke-width: 4; }
+circle.visited { fill: #aed6f1; }
+circle.current { fill: #f9e79f; }
+text { font-size: 13px; text-anchor: middle; dominant-baseline: central; }
+text.weight { fill: #555; font-size: 11px; }
+#log { font-family: monospace; white-space: pre; }
+";
+
+/// Steps through the trace, highlighting visited nodes and relaxed edges.
+const SCRIPT: &str = "
+let step = 0;
+const log = document.getElementById('log');
+function show(upTo) {
+  document.querySelectorAll('.visited, .current, .relaxed')
+    .forEach(el => el.classList.remove('visited', 'current', 'relaxed'));
+  for (let i = 0; i < upTo; i++) {
+    const e = TRACE[i];
+    if (e.node !== undefined) {
+      document.getElementById('n' + e.node).classList.add(i === upTo - 1 ? 'current' : 'visited');
+    }
+    if (e.edge !== undefined) {
+      document.getElementById('e' + e.edge).classList.add('relaxed');
+    }
+  }
+  log.textContent = TRACE.slice(0, upTo).map((e, i) => (i + 1) + '. ' + e.text).join('\\n');
+  step = upTo;
+}
+document.getElementById('step').onclick = () => show(Math.min(step + 1, TRACE.length));
+document.getElementById('reset').onclick = () => show(0);
+document.getElementById('play').onclick = () => {
+  show(0);
+  const timer = setInterval(() => {
+    if (step >= TRACE.length) { clearInterval(timer); } else { show(step + 1); }
+  }, 700);
+};
+";
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Renders a self-contained HTML page with the graph drawn as SVG.
+///
+/// Nodes are laid out on a circle, sorted by name. Edges along `path` are highlighted.
+/// Steps from `trace` (e.g. a [`Recorder`](crate::trace::Recorder)'s events) can be played
+/// back on the page; pass an empty slice for a static picture.
+pub fn render_html<K>(title: &str, graph: &NodeGraph<K, i32>, path: &[K], trace: &[Event]) -> String
+where
+    K: Display + Eq + Hash,
+{
+    let mut names: Vec<String> = graph.keys().map(K::to_string).collect();
+    names.sort();
+    let index: HashMap<&str, usize> = names
+        .iter()
+        .enumerate()
+        .map(|(i, n)| (n.as_str(), i))
+        .collect();
+    let position = |i: usize| {
+        let angle = TAU * i as f64 / names.len() as f64;
+        let r = SIZE / 2.0 - 2.0 * NODE_RADIUS;
+        (SIZE / 2.0 + r * angle.cos(), SIZE / 2.0 + r * angle.sin())
+    };
+
+    let path: Vec<usize> = path
+        .iter()
+        .filter_map(|n| index.get(n.to_string().as_str()).copied())
+        .collect();
+    let path_edges: HashSet<(usize, usize)> = path.windows(2).map(|w| (w[0], w[1])).collect();
+
+    let mut edges = Vec::new();
+    for (from, neighbors) in graph {
+        let from = index[from.to_string().as_str()];
+        for (to, &weight) in neighbors {
+            if let Some(&to) = index.get(to.to_string().as_str()) {
+                edges.push((from, to, weight));
+            }
+        }
+    }
+    edges.sort();
+    let edge_index: HashMap<(usize, usize), usize> = edges
+        .iter()
+        .enumerate()
+        .map(|(i, &(from, to, _))| ((from, to), i))
+        .collect();
+
+    let mut svg = String::new();
+    writeln!(
+        svg,
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{SIZE}" height="{SIZE}" viewBox="0 0 {SIZE} {SIZE}">
+<defs><marker id="arrow" viewBox="0 0 10 10" refX="10" refY="5" markerWidth="6" markerHeight="6" orient="auto-start-reverse"><path d="M 0 0 L 10 5 L 0 10 z" fill="#555"/></marker></defs>"##
+    )
+    .unwrap();
+    for (i, &(from, to, weight)) in edges.iter().enumerate() {
+        let ((x1, y1), (x2, y2)) = (position(from), position(to));
+        // Shortened by the node radius, so arrows end at the circle's border.
+        let len = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt().max(1.0);
+        let (dx, dy) = ((x2 - x1) / len * NODE_RADIUS, (y2 - y1) / len * NODE_RADIUS);
+        let class = if path_edges.contains(&(from, to)) {
+            "path"
+        } else {
+            ""
+        };
+        writeln!(
+            svg,
+            r#"<line id="e{i}" class="{class}" x1="{:.1}" y1="{:.1}" x2="{:.1}" y2="{:.1}" marker-end="url(#arrow)"/>"#,
+            x1 + dx,
+            y1 + dy,
+            x2 - dx,
+            y2 - dy
+        )
+        .unwrap();
+        let (mx, my) = ((x1 + x2) / 2.0 - dy / 2.0, (y1 + y2) / 2.0 + dx / 2.0);
+        writeln!(
+            svg,
+            r#"<text class="weight" x="{mx:.1}" y="{my:.1}">{weight}</text>"#
+        )
+        .unwrap();
+    }
+    for (i, name) in names.iter().enumerate() {
+        let (x, y) = position(i);
+        let class = if path.contains(&i) { "path" } else { "" };
+        writeln!(
+            svg,
+            r#"<circle id="n{i}" class="{class}" cx="{x:.1}" cy="{y:.1}" r="{NODE_RADIUS}"/><text x="{x:.1}" y="{y:.1}">{}</text>"#,
+            escape_html(name)
+        )
+        .unwrap();
+    }
+    svg.push_str("</svg>");
+
+    let steps: Vec<String> = trace
+        .iter()
+        .map(|event| {
+            let target = match event {
+                Event::Visit { node, .. } => {
+                    index.get(node.as_str()).map(|n| format!(r#","node":{n}"#))
+                }
+                Event::Relax { from, to, .. } => index
+                    .get(from.as_str())
+                    .zip(index.get(to.as_str()))
+                    .and_then(|(&f, &t)| edge_index.get(&(f, t)))
+                    .map(|e| format!(r#","edge":{e}"#)),
+                _ => None,
+            };
+            format!(
+                r#"{{"text":"{}"{}}}"#,
+                escape_json(&event.to_string()),
+                target.unwrap_or_default()
+            )
+        })
+        .collect();
+
+    let controls = if trace.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "<p><button id=\"play\">Play</button> <button id=\"step\">Step</button> \
+             <button id=\"reset\">Reset</button> {} steps</p>\n<div id=\"log\"></div>\n\
+             <script>\nconst TRACE = [{}];\n{SCRIPT}</script>\n",
+            trace.len(),
+            steps.join(",")
+        )
+    };
+
+    let title = escape_html(title);
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n\
+         <style>{STYLE}</style>\n</head>\n<body>\n<h1>{title}</h1>\n{svg}\n{controls}</body>\n</html>\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graphs::dijkstra::dejkstras_path_traced;
+    use crate::shorthands::new_h_map;
+    use crate::trace::Recorder;
+
+    #[test]
+    fn render_html_test() {
+        let (start, a, b, finish) = ("start", "a", "<b>", "fin");
+        let mut graph = NodeGraph::new();
+        graph.insert(start, new_h_map([(a, 6), (b, 2)]));
+        graph.insert(b, new_h_map([(a, 3), (finish, 5)]));
+        graph.insert(a, new_h_map([(finish, 1)]));
+        graph.insert(finish, HashMap::new());
+
+        let mut recorder = Recorder::new();
+        let path = dejkstras_path_traced(&graph, start, finish, &mut recorder).unwrap();
+        let html = render_html("Dijkstra", &graph, &path.nodes, recorder.events());
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("<circle").count(), 4);
+        assert_eq!(html.matches("<line").count(), 5);
+        // start → <b> → a → fin: its three edges and four nodes are highlighted.
+        assert_eq!(html.matches(r#"class="path" x1"#).count(), 3);
+        assert_eq!(html.matches(r#"class="path" cx"#).count(), 4);
+        assert_eq!(html.matches(r#"<circle id="n0" class="path""#).count(), 1);
+        assert!(html.contains("&lt;b&gt;"));
+        assert!(!html.contains("<b>"));
+        assert!(html.contains(r#"{"text":"visited start, final cost 0","node":3}"#));
+        assert!(html.contains(&format!("{} steps", recorder.events().len())));
+
+        let html = render_html("static", &graph, &[], &[]);
+        assert!(!html.contains("<script>"));
+        assert!(!html.contains(r#"class="path""#));
+    }
+}
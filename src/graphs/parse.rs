@@ -0,0 +1,169 @@
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::NodeGraph;
+use crate::collections::HashMap;
+use crate::error::AlgoError;
+
+/// Parses a directed, weighted graph given as one `from to weight` edge per line.
+///
+/// Fields may be separated by commas (CSV) or whitespace. Empty lines and lines starting
+/// with `#` are skipped, as is a header like `from,to,weight` before the first edge.
+/// Every node mentioned gets an entry in the graph, even if it has no outgoing edges.
+pub fn parse_edge_list(input: &str) -> Result<NodeGraph<String, i32>, AlgoError> {
+    let mut graph: NodeGraph<String, i32> = HashMap::new();
+    let mut first = true;
+
+    for (idx, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = if line.contains(',') {
+            line.split(',').map(str::trim).collect()
+        } else {
+            line.split_whitespace().collect()
+        };
+
+        let invalid =
+            |reason: String| AlgoError::InvalidInput(format!("line {}: {reason}", idx + 1));
+        let [from, to, weight] = fields[..] else {
+            let reason = format!("expected `from to weight`, got {} field(s)", fields.len());
+            return Err(invalid(reason));
+        };
+        if core::mem::take(&mut first) && is_header([from, to, weight]) {
+            continue;
+        }
+        let weight: i32 = weight
+            .parse()
+            .map_err(|_| invalid(format!("weight '{weight}' is not an integer")))?;
+        if from.is_empty() || to.is_empty() {
+            return Err(invalid("node names can't be empty".to_owned()));
+        }
+
+        graph.entry(to.to_owned()).or_default();
+        graph
+            .entry(from.to_owned())
+            .or_default()
+            .insert(to.to_owned(), weight);
+    }
+
+    Ok(graph)
+}
+
+/// Whether fields name the columns, e.g. `from to weight` or `source,target,cost`.
+fn is_header([from, to, weight]: [&str; 3]) -> bool {
+    let is_any = |field: &str, names: &[&str]| names.iter().any(|n| field.eq_ignore_ascii_case(n));
+    is_any(from, &["from", "source", "src"])
+        && is_any(to, &["to", "target", "dst"])
+        && is_any(weight, &["weight", "cost"])
+}
+
+/// Same as [`parse_edge_list`], but takes raw bytes, e.g. straight from a file or a fuzzer.
+///
+/// Never panics: bytes that aren't valid UTF-8 are reported as `AlgoError::InvalidInput`.
+pub fn try_parse_edge_list(input: &[u8]) -> Result<NodeGraph<String, i32>, AlgoError> {
+    parse_edge_list(utf8(input)?)
+}
+
+fn utf8(input: &[u8]) -> Result<&str, AlgoError> {
+    core::str::from_utf8(input).map_err(|e| {
+        let reason = format!("not valid UTF-8 after byte {}", e.valid_up_to());
+        AlgoError::InvalidInput(reason)
+    })
+}
+
+/// Parses a graph given as a JSON object of adjacency maps, e.g. `{"a": {"b": 1}, "b": {}}`.
+///
+/// Nodes that only appear as neighbors are added with no outgoing edges.
+#[cfg(feature = "json")]
+pub fn parse_json(input: &str) -> Result<NodeGraph<String, i32>, AlgoError> {
+    let mut graph: NodeGraph<String, i32> =
+        serde_json::from_str(input).map_err(|e| AlgoError::InvalidInput(e.to_string()))?;
+    let neighbors: Vec<String> = graph.values().flat_map(|n| n.keys().cloned()).collect();
+    for n in neighbors {
+        graph.entry(n).or_default();
+    }
+    Ok(graph)
+}
+
+/// Same as [`parse_json`], but takes raw bytes. Never panics.
+#[cfg(feature = "json")]
+pub fn try_parse_json(input: &[u8]) -> Result<NodeGraph<String, i32>, AlgoError> {
+    parse_json(utf8(input)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_edge_list_test() {
+        let input = "\
+from,to,weight
+start, a, 6
+start,b,2
+
+# Comments are fine too.
+b,a,3
+";
+        let graph = parse_edge_list(input).unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph["start"]["a"], 6);
+        assert_eq!(graph["b"]["a"], 3);
+        assert!(graph["a"].is_empty());
+
+        let graph = parse_edge_list("a b 1\nb c -2").unwrap();
+        assert_eq!(graph["b"]["c"], -2);
+
+        let graph = parse_edge_list("# edges\n\nSource Target Cost\na b 1").unwrap();
+        assert_eq!(graph["a"]["b"], 1);
+    }
+
+    #[test]
+    fn parse_edge_list_errors_test() {
+        assert_eq!(
+            parse_edge_list("a b 1\na b"),
+            Err(AlgoError::invalid_input(
+                "line 2: expected `from to weight`, got 2 field(s)"
+            ))
+        );
+        assert_eq!(
+            parse_edge_list("a b 1\na b x"),
+            Err(AlgoError::invalid_input(
+                "line 2: weight 'x' is not an integer"
+            ))
+        );
+        assert!(parse_edge_list(",b,1").is_err());
+        // Only column names make a header, not any line with a bad weight.
+        assert_eq!(
+            parse_edge_list("a b x\nb c 1"),
+            Err(AlgoError::invalid_input(
+                "line 1: weight 'x' is not an integer"
+            ))
+        );
+        assert!(parse_edge_list("a b 1\nfrom to weight").is_err());
+    }
+
+    #[test]
+    fn try_parse_edge_list_test() {
+        let graph = try_parse_edge_list(b"a b 1").unwrap();
+        assert_eq!(graph["a"]["b"], 1);
+        assert_eq!(
+            try_parse_edge_list(b"a b 1\n\xff b 2"),
+            Err(AlgoError::invalid_input("not valid UTF-8 after byte 6"))
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn parse_json_test() {
+        let graph = parse_json(r#"{"start": {"a": 6, "b": 2}, "b": {"a": 3}}"#).unwrap();
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph["start"]["b"], 2);
+        assert!(graph["a"].is_empty());
+        assert!(parse_json("[1, 2]").is_err());
+    }
+}
@@ -1,15 +1,77 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
 pub mod benchmarking {
+    use std::sync::OnceLock;
     use std::time::{Duration, Instant};
 
+    #[cfg(feature = "math")]
+    use crate::math::stats::RunningStats;
+
+    pub mod runner;
+
+    /// Source of monotonic time for measurements.
+    pub trait Clock {
+        /// Time passed since some fixed, arbitrary moment.
+        fn now(&self) -> Duration;
+    }
+
+    /// Clock backed by `std::time::Instant`.
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct StdClock;
+
+    impl Clock for StdClock {
+        fn now(&self) -> Duration {
+            static ORIGIN: OnceLock<Instant> = OnceLock::new();
+            ORIGIN.get_or_init(Instant::now).elapsed()
+        }
+    }
+
+    /// Clock backed by the browser's `performance.now()`.
+    ///
+    /// `Instant::now()` panics on `wasm32-unknown-unknown`, so this is used there instead.
+    #[cfg(feature = "wasm")]
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct PerformanceClock;
+
+    #[cfg(feature = "wasm")]
+    impl Clock for PerformanceClock {
+        /// Panics if there is no `window.performance`, e.g. outside of a browser.
+        fn now(&self) -> Duration {
+            let performance = web_sys::window()
+                .and_then(|w| w.performance())
+                .expect("`window.performance` should be available");
+            Duration::from_secs_f64(performance.now() / 1_000.0)
+        }
+    }
+
+    /// Clock used by `bench_*` functions: `performance.now()` in the browser, `Instant` elsewhere.
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    pub type DefaultClock = PerformanceClock;
+    #[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+    pub type DefaultClock = StdClock;
+
+    /// Measures the execution time of a function once using the given clock.
+    #[inline]
+    pub fn bench_once_with<C, F, T>(clock: &C, f: F) -> Duration
+    where
+        C: Clock + ?Sized,
+        F: FnOnce() -> T,
+    {
+        let start = clock.now();
+        f();
+        clock.now().saturating_sub(start)
+    }
+
     /// Measures the execution time of a function once and returns the duration.
     #[inline]
     pub fn bench_once<F, T>(f: F) -> Duration
     where
         F: FnOnce() -> T,
     {
-        let instant = Instant::now();
-        f();
-        instant.elapsed()
+        bench_once_with(&DefaultClock::default(), f)
     }
 
     /// Measure a function's execution time.
@@ -17,6 +79,7 @@ pub mod benchmarking {
     /// 'iterations' defines how many measurements there will be.
     /// Returns the lowest value, thereby minimizing OS influence on results.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(f)))]
     pub fn bench_times<F, T>(iterations: u32, mut f: F) -> Option<Duration>
     where
         F: FnMut() -> T,
@@ -25,17 +88,79 @@ pub mod benchmarking {
         let mut vec = Vec::with_capacity(cap);
         for _ in 0..iterations {
             let elapsed_time = bench_once(&mut f);
+            #[cfg(feature = "tracing")]
+            tracing::trace!(?elapsed_time, "measured");
             vec.push(elapsed_time);
         }
-        vec.into_iter().min()
+        let best = vec.into_iter().min();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?best, "finished");
+        best
+    }
+
+    /// Measure a function's execution time without storing every measurement.
+    ///
+    /// Each measurement (in nanoseconds) is fed into a [`RunningStats`],
+    /// so memory use stays constant no matter how many 'iterations' there are.
+    #[inline]
+    #[cfg(feature = "math")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(f)))]
+    pub fn bench_streaming<F, T>(iterations: u32, mut f: F) -> RunningStats
+    where
+        F: FnMut() -> T,
+    {
+        let mut stats = RunningStats::new();
+        for _ in 0..iterations {
+            let elapsed_time = bench_once(&mut f);
+            stats.push(elapsed_time.as_nanos() as f64);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(mean_ns = stats.mean(), min_ns = stats.min(), "finished");
+        stats
+    }
+
+    /// Same as [`bench_times`], but measurements run concurrently on all cores.
+    ///
+    /// Finishes long runs much faster, though measurements get noisier
+    /// since the threads compete for caches and memory bandwidth.
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(f)))]
+    pub fn bench_times_parallel<F, T>(iterations: u32, f: F) -> Option<Duration>
+    where
+        F: Fn() -> T + Sync,
+    {
+        use rayon::prelude::*;
+        (0..iterations)
+            .into_par_iter()
+            .map(|_| bench_once(&f))
+            .min()
+    }
+
+    /// Runs several benchmarks at once, one per thread, returning the best time of each.
+    #[cfg(feature = "parallel")]
+    pub fn bench_suite_parallel<F, T>(iterations: u32, benches: &[F]) -> Vec<Option<Duration>>
+    where
+        F: Fn() -> T + Sync,
+    {
+        use rayon::prelude::*;
+        benches
+            .par_iter()
+            .map(|f| bench_times(iterations, f))
+            .collect()
     }
 
     /// Calculates the number of iterations of `bench_once` that can be executed within a specified time limit.
     #[inline]
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
     pub fn calc_iterations(one_measurement_takes: Duration, desired_time: Duration) -> u32 {
-        let mut div = 1;
+        let mut div: u32 = 1;
         while desired_time / div > one_measurement_takes {
-            div *= 10
+            // A measurement below the clock's resolution (zero) or a huge budget would
+            // overflow `div`, so the count is capped instead.
+            let Some(next) = div.checked_mul(10) else {
+                break;
+            };
+            div = next
         }
         // For some reason, testing with an unmodified answer takes twice the expected time;
         // that's why there is a correction here.
@@ -43,49 +168,159 @@ pub mod benchmarking {
     }
 }
 
+/// Hash maps from `std`, or from `hashbrown` in `no_std` builds.
+pub mod collections {
+    #[cfg(not(feature = "std"))]
+    pub use hashbrown::{HashMap, HashSet};
+    #[cfg(feature = "std")]
+    pub use std::collections::{HashMap, HashSet};
+}
+
 pub mod shorthands {
-    use std::{collections::HashMap, hash::Hash};
+    use crate::collections::HashMap;
+    use core::hash::Hash;
 
-    pub fn at_idx(idx: usize) -> std::ops::RangeInclusive<usize> {
+    pub fn at_idx(idx: usize) -> core::ops::RangeInclusive<usize> {
         idx..=idx
     }
 
-    pub fn new_h_map<'a, K, const L: usize>(arr: [(&'a K, i32); L]) -> HashMap<&'a K, i32>
+    pub fn new_h_map<K, const L: usize>(arr: [(&K, i32); L]) -> HashMap<&K, i32>
     where
         K: Eq + Hash + ?Sized,
     {
         HashMap::from(arr)
     }
+
+    /// Counts how many times each item occurs.
+    pub fn counter<I>(items: I) -> HashMap<I::Item, usize>
+    where
+        I: IntoIterator,
+        I::Item: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        for item in items {
+            *counts.entry(item).or_insert(0) += 1;
+        }
+        counts
+    }
 }
 
 pub mod formatting {
+    use alloc::borrow::ToOwned;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use alloc::{format, vec};
+    use core::fmt::Write;
+
     pub fn is_emoji(c: char) -> bool {
-        match c {
+        matches!(
+            c,
             '\u{01F600}'..='\u{01F64F}'
-            | '\u{01F300}'..='\u{01F5FF}'
-            | '\u{01F680}'..='\u{01F6FF}'
-            | '\u{01F1E0}'..='\u{01F1FF}'
-            | '\u{002702}'..='\u{0027B0}'
-            | '\u{0024C2}'..='\u{01F251}' => true,
-            _ => false,
-        }
+                | '\u{01F300}'..='\u{01F5FF}'
+                | '\u{01F680}'..='\u{01F6FF}'
+                | '\u{01F1E0}'..='\u{01F1FF}'
+                | '\u{002702}'..='\u{0027B0}'
+                | '\u{0024C2}'..='\u{01F251}'
+        )
     }
 
     pub fn on_screen_len(s: &str) -> usize {
         let count = |acc, c| acc + if is_emoji(c) { 2 } else { 1 };
         s.chars().fold(0, count)
     }
+
+    /// Renders values as a line of block characters, scaled between their min and max.
+    #[cfg(feature = "std")]
+    pub fn sparkline(values: &[f64]) -> String {
+        const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let range = max - min;
+
+        let bar = |v: f64| {
+            if range > 0.0 {
+                BARS[((v - min) / range * (BARS.len() - 1) as f64).round() as usize]
+            } else {
+                BARS[0]
+            }
+        };
+        values.iter().map(|&v| bar(v)).collect()
+    }
+
+    /// Renders rows as a bordered table, padding cells by their on-screen length.
+    ///
+    /// Rows shorter than `headers` are padded with empty cells.
+    pub fn render_table<S: AsRef<str>>(headers: &[&str], rows: &[Vec<S>]) -> String {
+        let cell = |row: &[S], i: usize| row.get(i).map_or("", |s| s.as_ref()).to_owned();
+        let widths: Vec<usize> = (0..headers.len())
+            .map(|i| {
+                rows.iter()
+                    .map(|r| on_screen_len(&cell(r, i)))
+                    .fold(on_screen_len(headers[i]), usize::max)
+            })
+            .collect();
+
+        let line = |cells: Vec<String>| {
+            let padded = cells.iter().zip(&widths).map(|(c, &w)| {
+                let pad = w - on_screen_len(c);
+                format!("{c}{}", " ".repeat(pad))
+            });
+            format!("| {} |", padded.collect::<Vec<_>>().join(" | "))
+        };
+
+        let header = line(headers.iter().map(|&h| h.to_owned()).collect());
+        let border = String::from_iter(vec!['-'; on_screen_len(&header)]);
+        let mut out = vec![border.clone(), header, border.clone()];
+        for row in rows {
+            out.push(line((0..headers.len()).map(|i| cell(row, i)).collect()));
+        }
+        out.push(border);
+        out.join("\n")
+    }
+
+    /// Escapes `s` for use inside a double-quoted JSON (or JavaScript) string.
+    ///
+    /// `<` is escaped too, so the result can be embedded in an HTML `<script>` tag.
+    pub fn escape_json(s: &str) -> String {
+        let mut out = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '<' => out.push_str("\\u003c"),
+                c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+                c => out.push(c),
+            }
+        }
+        out
+    }
 }
 
+pub mod error;
+#[cfg(feature = "graphs")]
+pub mod graphs;
+#[cfg(feature = "math")]
+pub mod math;
+#[cfg(feature = "ml")]
+pub mod ml;
+#[cfg(feature = "pyo3")]
+mod python;
+pub mod rng;
+pub mod testing;
+pub mod trace;
+
 #[cfg(test)]
 mod tests {
-    use crate::formatting::{is_emoji, on_screen_len};
+    #[cfg(feature = "std")]
+    use crate::benchmarking::{bench_once_with, Clock};
+    #[cfg(feature = "std")]
+    use crate::formatting::sparkline;
+    use crate::formatting::{escape_json, is_emoji, on_screen_len, render_table};
+    use crate::shorthands::counter;
 
     #[test]
     fn is_emoji_test() {
-        "✨✅🚧❌"
-            .chars()
-            .for_each(|c| assert_eq!(is_emoji(c), true));
+        "✨✅🚧❌".chars().for_each(|c| assert!(is_emoji(c)));
     }
 
     #[test]
@@ -102,4 +337,87 @@ mod tests {
         let s = "🚧 Oh, shieeet, answer is 6 instead of 5 ❌";
         assert_eq!(on_screen_len(s), 43);
     }
+
+    #[test]
+    fn escape_json_test() {
+        assert_eq!(escape_json("plain ✅"), "plain ✅");
+        assert_eq!(escape_json(r#"a "b" \ c"#), r#"a \"b\" \\ c"#);
+        assert_eq!(escape_json("</script>\n\t"), r"\u003c/script>\u000a\u0009");
+    }
+
+    #[test]
+    fn render_table_test() {
+        let rows = vec![vec!["a", "✅"], vec!["long cell"]];
+        let expected = "\
+------------------
+| name      | ok |
+------------------
+| a         | ✅ |
+| long cell |    |
+------------------";
+        assert_eq!(render_table(&["name", "ok"], &rows), expected);
+    }
+
+    #[cfg(all(feature = "std", feature = "math"))]
+    #[test]
+    fn bench_streaming_test() {
+        use crate::benchmarking::bench_streaming;
+
+        let stats = bench_streaming(50, || (0..100).sum::<u64>());
+        assert_eq!(stats.count(), 50);
+        assert!(stats.min() <= stats.mean() && stats.mean() <= stats.max());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sparkline_test() {
+        assert_eq!(
+            sparkline(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]),
+            "▁▂▃▄▅▆▇█"
+        );
+        assert_eq!(sparkline(&[10.0, 0.0, 5.0]), "█▁▅");
+        assert_eq!(sparkline(&[3.0, 3.0]), "▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn counter_test() {
+        let counts = counter("abracadabra".chars());
+        assert_eq!(counts[&'a'], 5);
+        assert_eq!(counts[&'b'], 2);
+        assert_eq!(counts.get(&'z'), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn bench_once_with_clock_test() {
+        use std::cell::Cell;
+        use std::time::Duration;
+
+        /// Advances by one millisecond every time it's read.
+        struct FakeClock(Cell<u64>);
+
+        impl Clock for FakeClock {
+            fn now(&self) -> Duration {
+                self.0.set(self.0.get() + 1);
+                Duration::from_millis(self.0.get())
+            }
+        }
+
+        let clock = FakeClock(Cell::new(0));
+        assert_eq!(bench_once_with(&clock, || ()), Duration::from_millis(1));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn bench_parallel_test() {
+        use crate::benchmarking::{bench_suite_parallel, bench_times_parallel};
+
+        assert!(bench_times_parallel(20, || (0..100).sum::<u64>()).is_some());
+        assert_eq!(bench_times_parallel(0, || ()), None);
+
+        let benches = [|| (0..10).sum::<u64>(), || (0..1_000).sum::<u64>()];
+        let res = bench_suite_parallel(10, &benches);
+        assert!(res.iter().all(Option::is_some));
+    }
 }
@@ -0,0 +1,10 @@
+pub mod big_uint;
+pub mod combinatorics;
+pub mod fibonacci;
+pub mod matrix;
+pub mod modular;
+pub mod monte_carlo;
+pub mod ntt;
+pub mod primes;
+pub mod roots;
+pub mod stats;
@@ -0,0 +1,396 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Mul, Sub};
+use core::str::FromStr;
+
+use crate::error::AlgoError;
+
+/// Operands shorter than this (in limbs) are multiplied with the schoolbook method,
+/// since Karatsuba's bookkeeping outweighs its gains on small numbers.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Arbitrary-precision unsigned integer.
+///
+/// Stored as little-endian base `2^32` limbs without trailing zeroes, so zero is an empty vector.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+    limbs
+}
+
+/// Adds `b` into `acc` starting at limb `offset`. `acc` must be long enough to hold the result.
+fn add_at(acc: &mut [u32], b: &[u32], offset: usize) {
+    let mut carry = 0u64;
+    let mut i = offset;
+    for &x in b {
+        let sum = acc[i] as u64 + x as u64 + carry;
+        acc[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+    while carry != 0 {
+        let sum = acc[i] as u64 + carry;
+        acc[i] = sum as u32;
+        carry = sum >> 32;
+        i += 1;
+    }
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (long, short) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut res = long.to_vec();
+    res.push(0);
+    add_at(&mut res, short, 0);
+    trim(res)
+}
+
+/// `a - b`, requires `a >= b`.
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = a.to_vec();
+    let mut borrow = 0i64;
+    for (i, r) in res.iter_mut().enumerate() {
+        let diff = *r as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        (*r, borrow) = if diff < 0 {
+            ((diff + (1 << 32)) as u32, 1)
+        } else {
+            (diff as u32, 0)
+        };
+    }
+    debug_assert_eq!(borrow, 0, "subtraction underflow");
+    trim(res)
+}
+
+fn cmp_limbs(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+        for (j, &y) in b.iter().enumerate() {
+            let cur = res[i + j] as u64 + x as u64 * y as u64 + carry;
+            res[i + j] = cur as u32;
+            carry = cur >> 32;
+        }
+        res[i + b.len()] = carry as u32;
+    }
+    trim(res)
+}
+
+fn karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b);
+    }
+    // a = a1 * B^m + a0, b = b1 * B^m + b0
+    let m = a.len().max(b.len()) / 2;
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let (b0, b1) = b.split_at(m.min(b.len()));
+    let (a0, b0) = (trim(a0.to_vec()), trim(b0.to_vec()));
+
+    let z0 = karatsuba(&a0, &b0);
+    let z2 = karatsuba(a1, b1);
+    // z1 = (a0 + a1)(b0 + b1) - z0 - z2 = a0 * b1 + a1 * b0
+    let z1 = karatsuba(&add_limbs(&a0, a1), &add_limbs(&b0, b1));
+    let z1 = sub_limbs(&sub_limbs(&z1, &z0), &z2);
+
+    let mut res = vec![0u32; a.len() + b.len() + 1];
+    add_at(&mut res, &z0, 0);
+    add_at(&mut res, &z1, m);
+    add_at(&mut res, &z2, 2 * m);
+    trim(res)
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Builds a number from little-endian base `2^32` limbs.
+    pub fn from_limbs(limbs: Vec<u32>) -> Self {
+        Self { limbs: trim(limbs) }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Multiplies using the `O(n^2)` schoolbook method.
+    pub fn mul_schoolbook(&self, rhs: &BigUint) -> BigUint {
+        Self {
+            limbs: schoolbook(&self.limbs, &rhs.limbs),
+        }
+    }
+
+    /// Multiplies using Karatsuba's `O(n^1.585)` method, falling back to schoolbook for small operands.
+    pub fn mul_karatsuba(&self, rhs: &BigUint) -> BigUint {
+        Self {
+            limbs: karatsuba(&self.limbs, &rhs.limbs),
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` if the result would be negative.
+    pub fn checked_sub(&self, rhs: &BigUint) -> Option<BigUint> {
+        match cmp_limbs(&self.limbs, &rhs.limbs) {
+            Ordering::Less => None,
+            _ => Some(Self {
+                limbs: sub_limbs(&self.limbs, &rhs.limbs),
+            }),
+        }
+    }
+
+    fn mul_small_add(&mut self, mul: u32, add: u32) {
+        let mut carry = add as u64;
+        for limb in &mut self.limbs {
+            let cur = *limb as u64 * mul as u64 + carry;
+            *limb = cur as u32;
+            carry = cur >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+        self.limbs = trim(core::mem::take(&mut self.limbs));
+    }
+
+    /// Divides in place by `div`, returning the remainder.
+    fn div_small(&mut self, div: u32) -> u32 {
+        let mut rem = 0u64;
+        for limb in self.limbs.iter_mut().rev() {
+            let cur = (rem << 32) | *limb as u64;
+            *limb = (cur / div as u64) as u32;
+            rem = cur % div as u64;
+        }
+        self.limbs = trim(core::mem::take(&mut self.limbs));
+        rem as u32
+    }
+}
+
+/// Calculates `n!` without overflowing.
+pub fn factorial(n: u32) -> BigUint {
+    let mut acc = BigUint::from(1u64);
+    (2..=n).for_each(|i| acc.mul_small_add(i, 0));
+    acc
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self {
+            limbs: trim(vec![value as u32, (value >> 32) as u32]),
+        }
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_limbs(&self.limbs, &other.limbs)
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        BigUint {
+            limbs: add_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if `rhs > self`. See [`BigUint::checked_sub`].
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        self.mul_karatsuba(rhs)
+    }
+}
+
+/// Error returned when parsing a [`BigUint`] from a decimal string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigUintError {
+    Empty,
+    InvalidDigit(char),
+}
+
+impl fmt::Display for ParseBigUintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidDigit(c) => write!(f, "invalid digit {c:?} found in string"),
+        }
+    }
+}
+
+impl core::error::Error for ParseBigUintError {}
+
+impl From<ParseBigUintError> for AlgoError {
+    fn from(err: ParseBigUintError) -> Self {
+        Self::InvalidInput(err.to_string())
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigUintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseBigUintError::Empty);
+        }
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(ParseBigUintError::InvalidDigit(c));
+        }
+
+        let mut res = BigUint::zero();
+        // Consume up to 9 digits at a time, since `10^9` still fits into a limb.
+        for chunk in s.as_bytes().chunks(9) {
+            let value = chunk.iter().fold(0, |acc, d| acc * 10 + (d - b'0') as u32);
+            res.mul_small_add(10u32.pow(chunk.len() as u32), value);
+        }
+        Ok(res)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+        let (mut n, mut chunks) = (self.clone(), Vec::new());
+        while !n.is_zero() {
+            chunks.push(n.div_small(1_000_000_000));
+        }
+        let mut s = chunks.pop().unwrap_or(0).to_string();
+        chunks
+            .iter()
+            .rev()
+            .for_each(|c| s.push_str(&format!("{c:09}")));
+        f.pad_integral(true, "", &s)
+    }
+}
+
+/// Serialized as a decimal string, so numbers of any size survive formats like JSON.
+#[cfg(feature = "serde")]
+impl serde::Serialize for BigUint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for BigUint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::Rng;
+
+    fn big(s: &str) -> BigUint {
+        s.parse().unwrap()
+    }
+
+    fn random_big(rng: &mut Rng, limbs: usize) -> BigUint {
+        BigUint {
+            limbs: trim((0..limbs).map(|_| rng.next_u32()).collect()),
+        }
+    }
+
+    #[test]
+    fn parse_and_display_test() {
+        for s in ["0", "1", "999999999", "1000000000", "18446744073709551616"] {
+            assert_eq!(big(s).to_string(), s);
+        }
+        assert_eq!(big("000123").to_string(), "123");
+        assert_eq!(BigUint::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(format!("{:>5}", big("42")), "   42");
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigUintError::Empty));
+        assert_eq!(
+            "12a".parse::<BigUint>(),
+            Err(ParseBigUintError::InvalidDigit('a'))
+        );
+        assert_eq!(
+            AlgoError::from(ParseBigUintError::Empty).to_string(),
+            "invalid input: cannot parse integer from empty string"
+        );
+    }
+
+    #[test]
+    fn add_sub_test() {
+        let a = big("18446744073709551615");
+        let sum = &a + &BigUint::from(1);
+        assert_eq!(sum.to_string(), "18446744073709551616");
+        assert_eq!(&sum - &BigUint::from(1), a);
+        assert_eq!(&a - &a, BigUint::zero());
+        assert_eq!(BigUint::from(1).checked_sub(&a), None);
+        assert!(sum > a);
+    }
+
+    #[test]
+    fn mul_test() {
+        let a = big("123456789012345678901234567890");
+        let b = big("987654321098765432109876543210");
+        let expected = "121932631137021795226185032733622923332237463801111263526900";
+        assert_eq!((&a * &b).to_string(), expected);
+        assert_eq!(a.mul_schoolbook(&b).to_string(), expected);
+        assert_eq!((&a * &BigUint::zero()), BigUint::zero());
+
+        let mut rng = Rng::new(12);
+        for (la, lb) in [(100, 100), (150, 40), (33, 200), (64, 1)] {
+            let (a, b) = (random_big(&mut rng, la), random_big(&mut rng, lb));
+            assert_eq!(a.mul_karatsuba(&b), a.mul_schoolbook(&b));
+        }
+    }
+
+    #[test]
+    fn factorial_test() {
+        assert_eq!(factorial(0).to_string(), "1");
+        assert_eq!(factorial(20).to_string(), "2432902008176640000");
+        assert_eq!(
+            factorial(30).to_string(),
+            "265252859812191058636308480000000"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let n = factorial(25);
+        let json = serde_json::to_string(&n).unwrap();
+        assert_eq!(json, "\"15511210043330985984000000\"");
+        assert_eq!(serde_json::from_str::<BigUint>(&json).unwrap(), n);
+        assert!(serde_json::from_str::<BigUint>("\"12x\"").is_err());
+    }
+}
@@ -0,0 +1,211 @@
+use super::modular::{mod_inv, ModInvError};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Calculates `n choose k`, returning `None` if the result doesn't fit into `u64`.
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+
+    // `C(n, i)` grows monotonically for `i <= n / 2`, so if an intermediate value
+    // overflows, the final answer would too.
+    let mut acc: u128 = 1;
+    for i in 1..=k as u128 {
+        acc = acc * (n as u128 - k as u128 + i) / i;
+        if acc > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(acc as u64)
+}
+
+/// Number of ordered arrangements of `k` items out of `n`, i.e. `n! / (n - k)!`.
+///
+/// Returns `None` on overflow.
+pub fn permutations(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    (n - k + 1..=n).try_fold(1u64, |acc, x| acc.checked_mul(x))
+}
+
+/// Precomputed factorials and inverse factorials modulo a prime.
+///
+/// After `O(n)` setup, every factorial, binomial or permutation query is `O(1)`.
+#[derive(Debug, Clone)]
+pub struct FactorialTable {
+    modulus: u32,
+    fact: Vec<u32>,
+    inv_fact: Vec<u32>,
+}
+
+impl FactorialTable {
+    /// Builds tables for all values in `0..=max_n`.
+    ///
+    /// `modulus` should be a prime greater than `max_n`, otherwise some factorials
+    /// have no inverse and an error is returned. So is a modulus of 0 or 1.
+    pub fn new(max_n: usize, modulus: u32) -> Result<Self, ModInvError> {
+        if modulus <= 1 {
+            return Err(ModInvError::InvalidModulus(modulus.into()));
+        }
+        let m = modulus as u64;
+        let mut fact = Vec::with_capacity(max_n + 1);
+        fact.push((1 % m) as u32);
+        for i in 1..=max_n {
+            fact.push((fact[i - 1] as u64 * i as u64 % m) as u32);
+        }
+
+        let mut inv_fact = vec![0; max_n + 1];
+        inv_fact[max_n] = mod_inv(fact[max_n] as i64, m as i64)? as u32;
+        for i in (1..=max_n).rev() {
+            inv_fact[i - 1] = (inv_fact[i] as u64 * i as u64 % m) as u32;
+        }
+
+        Ok(Self {
+            modulus,
+            fact,
+            inv_fact,
+        })
+    }
+
+    pub fn modulus(&self) -> u32 {
+        self.modulus
+    }
+
+    /// `n! mod p`. Panics if `n` is outside of the precomputed range.
+    pub fn factorial(&self, n: usize) -> u32 {
+        self.fact[n]
+    }
+
+    /// `(n choose k) mod p`. Panics if `n` is outside of the precomputed range.
+    pub fn binomial(&self, n: usize, k: usize) -> u32 {
+        if k > n {
+            return 0;
+        }
+        let m = self.modulus as u64;
+        let res = self.fact[n] as u64 * self.inv_fact[k] as u64 % m * self.inv_fact[n - k] as u64;
+        (res % m) as u32
+    }
+
+    /// `n! / (n - k)! mod p`. Panics if `n` is outside of the precomputed range.
+    pub fn permutations(&self, n: usize, k: usize) -> u32 {
+        if k > n {
+            return 0;
+        }
+        (self.fact[n] as u64 * self.inv_fact[n - k] as u64 % self.modulus as u64) as u32
+    }
+}
+
+/// Iterator over all `k`-element combinations of a slice, in lexicographic order of indices.
+#[derive(Debug, Clone)]
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    indices: Vec<usize>,
+    done: bool,
+}
+
+/// Creates an iterator over all `k`-combinations of `items`.
+///
+/// Yields exactly `binomial(items.len(), k)` combinations; for `k == 0` that's a single empty one.
+pub fn combinations<T>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (0..k).collect(),
+        done: k > items.len(),
+    }
+}
+
+impl<'a, T> Iterator for Combinations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let current = self.indices.iter().map(|&i| &self.items[i]).collect();
+
+        // Find the rightmost index that can still be moved forward.
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rev().find(|&i| self.indices[i] != i + n - k) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+
+        Some(current)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binomial_test() {
+        assert_eq!(binomial(5, 2), Some(10));
+        assert_eq!(binomial(5, 0), Some(1));
+        assert_eq!(binomial(5, 6), Some(0));
+        assert_eq!(binomial(0, 0), Some(1));
+        assert_eq!(binomial(64, 32), Some(1_832_624_140_942_590_534));
+        assert_eq!(binomial(67, 33), Some(14_226_520_737_620_288_370));
+        assert_eq!(binomial(68, 34), None);
+        assert_eq!(binomial(u64::MAX, 1), Some(u64::MAX));
+    }
+
+    #[test]
+    fn permutations_test() {
+        assert_eq!(permutations(5, 2), Some(20));
+        assert_eq!(permutations(5, 0), Some(1));
+        assert_eq!(permutations(3, 4), Some(0));
+        assert_eq!(permutations(20, 20), Some(2_432_902_008_176_640_000));
+        assert_eq!(permutations(21, 21), None);
+    }
+
+    #[test]
+    fn factorial_table_test() {
+        let m = 1_000_000_007;
+        let table = FactorialTable::new(1_000, m).unwrap();
+        assert_eq!(table.factorial(0), 1);
+        assert_eq!(table.factorial(10), 3_628_800);
+        for n in 0..60 {
+            for k in 0..=n + 1 {
+                let exact = binomial(n, k).unwrap() % m as u64;
+                assert_eq!(table.binomial(n as usize, k as usize) as u64, exact);
+            }
+        }
+        assert_eq!(table.binomial(1_000, 500), 159_835_829);
+        assert_eq!(table.permutations(10, 3), 720);
+        assert_eq!(table.permutations(3, 10), 0);
+
+        assert!(FactorialTable::new(10, 7).is_err());
+        assert_eq!(
+            FactorialTable::new(3, 0).unwrap_err(),
+            ModInvError::InvalidModulus(0)
+        );
+        assert_eq!(
+            FactorialTable::new(0, 1).unwrap_err(),
+            ModInvError::InvalidModulus(1)
+        );
+    }
+
+    #[test]
+    fn combinations_test() {
+        let items = ['a', 'b', 'c', 'd'];
+        let combs: Vec<String> = combinations(&items, 2)
+            .map(|c| c.into_iter().collect())
+            .collect();
+        assert_eq!(combs, ["ab", "ac", "ad", "bc", "bd", "cd"]);
+
+        assert_eq!(combinations(&items, 0).count(), 1);
+        assert_eq!(combinations(&items, 4).count(), 1);
+        assert_eq!(combinations(&items, 5).count(), 0);
+        let n = (0..10).collect::<Vec<_>>();
+        assert_eq!(combinations(&n, 4).count() as u64, binomial(10, 4).unwrap());
+    }
+}
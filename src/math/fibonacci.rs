@@ -0,0 +1,79 @@
+use alloc::collections::BTreeMap;
+
+use super::matrix::Matrix;
+
+/// Largest `n` for which `F(n)` fits into `u64`.
+const MAX_N: u64 = 93;
+
+/// Naive recursive Fibonacci. Takes exponential time, so keep `n` small.
+///
+/// # Panics
+///
+/// If `n > 93`, as `F(n)` doesn't fit into `u64`. See [`fibonacci`] for a checked version.
+pub fn fibonacci_naive(n: u64) -> u64 {
+    assert!(n <= MAX_N, "F({n}) doesn't fit into u64");
+    match n {
+        0 | 1 => n,
+        _ => fibonacci_naive(n - 1) + fibonacci_naive(n - 2),
+    }
+}
+
+/// Recursive Fibonacci that caches already computed values. `O(n)` time and memory.
+///
+/// # Panics
+///
+/// If `n > 93`, as `F(n)` doesn't fit into `u64`. See [`fibonacci`] for a checked version.
+pub fn fibonacci_memo(n: u64) -> u64 {
+    assert!(n <= MAX_N, "F({n}) doesn't fit into u64");
+    fn go(n: u64, memo: &mut BTreeMap<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        if let Some(&res) = memo.get(&n) {
+            return res;
+        }
+        let res = go(n - 1, memo) + go(n - 2, memo);
+        memo.insert(n, res);
+        res
+    }
+    go(n, &mut BTreeMap::new())
+}
+
+/// Fibonacci via matrix exponentiation in `O(log n)` multiplications.
+///
+/// Uses the identity `[[1, 1], [1, 0]]^(n - 1) = [[F(n), F(n - 1)], [F(n - 1), F(n - 2)]]`.
+/// Returns `None` if the answer doesn't fit into `u64` (`n > 93`).
+pub fn fibonacci(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(0);
+    }
+    let q = Matrix::from_rows([[1, 1], [1, 0]]);
+    q.checked_pow(n - 1).map(|m| m[(0, 0)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fibonacci_test() {
+        let first = [0, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89];
+        for (n, &f) in first.iter().enumerate() {
+            assert_eq!(fibonacci_naive(n as u64), f);
+            assert_eq!(fibonacci_memo(n as u64), f);
+            assert_eq!(fibonacci(n as u64), Some(f));
+        }
+        for n in 0..=MAX_N {
+            assert_eq!(fibonacci(n), Some(fibonacci_memo(n)));
+        }
+        assert_eq!(fibonacci(93), Some(12_200_160_415_121_876_738));
+        assert_eq!(fibonacci(94), None);
+        assert_eq!(fibonacci_memo(93), 12_200_160_415_121_876_738);
+    }
+
+    #[test]
+    #[should_panic(expected = "F(94) doesn't fit into u64")]
+    fn fibonacci_memo_overflow_test() {
+        fibonacci_memo(94);
+    }
+}
@@ -0,0 +1,141 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::{Index, IndexMut, Mul};
+
+/// Small dense matrix of unsigned integers stored in row-major order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<u64>,
+}
+
+impl Matrix {
+    /// Creates a `rows x cols` matrix filled with zeroes.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![0; rows * cols],
+        }
+    }
+
+    /// Creates an `n x n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        (0..n).for_each(|i| m[(i, i)] = 1);
+        m
+    }
+
+    /// Creates a matrix from an array of rows.
+    pub fn from_rows<const R: usize, const C: usize>(rows: [[u64; C]; R]) -> Self {
+        Self {
+            rows: R,
+            cols: C,
+            data: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Multiplies two matrices, returning `None` if any entry overflows.
+    ///
+    /// Panics if the dimensions don't match.
+    pub fn checked_mul(&self, rhs: &Matrix) -> Option<Matrix> {
+        assert_eq!(self.cols, rhs.rows, "dimension mismatch");
+        let mut res = Matrix::zeros(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for j in 0..rhs.cols {
+                let mut acc = 0u64;
+                for k in 0..self.cols {
+                    acc = acc.checked_add(self[(i, k)].checked_mul(rhs[(k, j)])?)?;
+                }
+                res[(i, j)] = acc;
+            }
+        }
+        Some(res)
+    }
+
+    /// Raises a square matrix to the power `exp` using binary exponentiation,
+    /// so only `O(log exp)` multiplications are needed.
+    ///
+    /// Returns `None` on overflow. Panics if the matrix isn't square.
+    pub fn checked_pow(&self, mut exp: u64) -> Option<Matrix> {
+        assert_eq!(
+            self.rows, self.cols,
+            "only square matrices can be raised to a power"
+        );
+        let (mut base, mut acc) = (self.clone(), Matrix::identity(self.rows));
+        while exp > 0 {
+            if exp & 1 == 1 {
+                acc = acc.checked_mul(&base)?;
+            }
+            exp >>= 1;
+            // Skipping the last squaring avoids spurious overflows.
+            if exp > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(acc)
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = u64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &u64 {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut u64 {
+        assert!(row < self.rows && col < self.cols, "index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl Mul for &Matrix {
+    type Output = Matrix;
+
+    /// Panics on overflow or dimension mismatch. See [`Matrix::checked_mul`].
+    fn mul(self, rhs: &Matrix) -> Matrix {
+        self.checked_mul(rhs)
+            .expect("matrix multiplication overflowed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matrix_mul_test() {
+        let a = Matrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let b = Matrix::from_rows([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(&a * &b, Matrix::from_rows([[58, 64], [139, 154]]));
+        assert_eq!(&a * &Matrix::identity(3), a);
+
+        let big = Matrix::from_rows([[u64::MAX, 1], [0, 1]]);
+        assert_eq!(big.checked_mul(&big), None);
+    }
+
+    #[test]
+    fn matrix_pow_test() {
+        let m = Matrix::from_rows([[1, 1], [1, 0]]);
+        assert_eq!(m.checked_pow(0), Some(Matrix::identity(2)));
+        assert_eq!(m.checked_pow(1), Some(m.clone()));
+        assert_eq!(
+            m.checked_pow(10),
+            Some(Matrix::from_rows([[89, 55], [55, 34]]))
+        );
+        assert_eq!(m.checked_pow(200), None);
+    }
+}
@@ -0,0 +1,159 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use crate::error::AlgoError;
+
+/// Extended Euclidean algorithm.
+///
+/// Returns `(g, x, y)` such that `a * x + b * y == g`, where `g` is the greatest common divisor.
+///
+/// Works for the whole `i64` range. `g` is unsigned, since `gcd(i64::MIN, 0)` is `2^63`.
+pub fn ext_gcd(a: i64, b: i64) -> (u64, i64, i64) {
+    // Widened, so negating or dividing `i64::MIN` can't overflow.
+    let (mut old_r, mut r) = (i128::from(a), i128::from(b));
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    // Bézout coefficients are bounded by `|b| / g` and `|a| / g`, so they fit into `i64`.
+    (old_r as u64, old_x as i64, old_y as i64)
+}
+
+/// Calculates `base^exp mod m` using binary exponentiation.
+///
+/// Intermediate products are widened to `u128`, so any 64-bit modulus is fine.
+/// Panics if `m` is zero.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    assert_ne!(m, 0, "modulus must be non-zero");
+    let m = m as u128;
+    let (mut base, mut acc) = (base as u128 % m, 1 % m);
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            acc = acc * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+
+    acc as u64
+}
+
+/// Reasons why a modular inverse can't be computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ModInvError {
+    /// Modulus is zero (or one, where every residue is zero).
+    InvalidModulus(i64),
+    /// `a` and `m` share a common divisor, so `a` has no inverse modulo `m`.
+    NotCoprime { a: i64, m: i64, gcd: i64 },
+}
+
+impl fmt::Display for ModInvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidModulus(m) => write!(f, "modulus must be greater than 1, got {m}"),
+            Self::NotCoprime { a, m, gcd } => {
+                write!(f, "{a} has no inverse modulo {m}: gcd({a}, {m}) = {gcd}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ModInvError {}
+
+impl From<ModInvError> for AlgoError {
+    fn from(err: ModInvError) -> Self {
+        Self::InvalidInput(err.to_string())
+    }
+}
+
+/// Finds `x` in `0..m` such that `a * x ≡ 1 (mod m)`.
+///
+/// Negative `a` is normalized first, so `mod_inv(-3, 7)` is the same as `mod_inv(4, 7)`.
+pub fn mod_inv(a: i64, m: i64) -> Result<i64, ModInvError> {
+    if m <= 1 {
+        return Err(ModInvError::InvalidModulus(m));
+    }
+    let (gcd, x, _) = ext_gcd(a.rem_euclid(m), m);
+    if gcd != 1 {
+        // Divides `m`, so it fits.
+        let gcd = gcd as i64;
+        return Err(ModInvError::NotCoprime { a, m, gcd });
+    }
+    Ok(x.rem_euclid(m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ext_gcd_test() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        let cases = [
+            (240, 46),
+            (46, 240),
+            (17, 5),
+            (0, 9),
+            (9, 0),
+            (-12, 18),
+            (min, 0),
+            (min, -1),
+            (min, min),
+            (min, max),
+            (max, min + 2),
+            (1, min),
+        ];
+        for (a, b) in cases {
+            let (g, x, y) = ext_gcd(a, b);
+            let lhs = i128::from(a) * i128::from(x) + i128::from(b) * i128::from(y);
+            assert_eq!(lhs, i128::from(g), "ext_gcd({a}, {b})");
+        }
+        assert_eq!(ext_gcd(min, 0).0, 1 << 63);
+        assert_eq!(ext_gcd(240, 46).0, 2);
+        assert_eq!(ext_gcd(-12, 18).0, 6);
+    }
+
+    #[test]
+    fn mod_pow_test() {
+        assert_eq!(mod_pow(2, 10, 1_000), 24);
+        assert_eq!(mod_pow(3, 0, 7), 1);
+        assert_eq!(mod_pow(3, 0, 1), 0);
+        assert_eq!(mod_pow(0, 0, 5), 1);
+        // Fermat's little theorem.
+        assert_eq!(mod_pow(123_456_789, 998_244_352, 998_244_353), 1);
+        // Doesn't overflow with moduli close to `u64::MAX`.
+        let m = u64::MAX - 58;
+        assert_eq!(mod_pow(m - 1, 2, m), 1);
+    }
+
+    #[test]
+    fn mod_inv_test() {
+        assert_eq!(mod_inv(3, 7), Ok(5));
+        assert_eq!(mod_inv(-3, 7), Ok(2));
+        assert_eq!(mod_inv(10, 17).map(|x| x * 10 % 17), Ok(1));
+        assert_eq!(
+            mod_inv(6, 9),
+            Err(ModInvError::NotCoprime { a: 6, m: 9, gcd: 3 })
+        );
+        assert_eq!(mod_inv(1, 1), Err(ModInvError::InvalidModulus(1)));
+        assert_eq!(
+            mod_inv(6, 9).unwrap_err().to_string(),
+            "6 has no inverse modulo 9: gcd(6, 9) = 3"
+        );
+        assert_eq!(
+            AlgoError::from(ModInvError::InvalidModulus(0)),
+            AlgoError::invalid_input("modulus must be greater than 1, got 0")
+        );
+    }
+}
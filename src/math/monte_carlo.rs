@@ -0,0 +1,132 @@
+#[cfg(feature = "std")]
+use crate::benchmarking::bench_once;
+#[cfg(feature = "std")]
+use crate::formatting::render_table;
+
+/// Estimates π by throwing `samples` random points into the unit square
+/// and counting how many land inside the quarter circle.
+///
+/// `uniform` must return independent values in `[0, 1)`.
+pub fn estimate_pi<U>(samples: u64, mut uniform: U) -> f64
+where
+    U: FnMut() -> f64,
+{
+    if samples == 0 {
+        return 0.0;
+    }
+    let inside = (0..samples)
+        .filter(|_| {
+            let (x, y) = (uniform(), uniform());
+            x * x + y * y <= 1.0
+        })
+        .count();
+    4.0 * inside as f64 / samples as f64
+}
+
+/// Estimates the integral of `f` over `[a, b]` by averaging it at `samples` random points.
+///
+/// `uniform` must return independent values in `[0, 1)`.
+pub fn integrate<F, U>(f: F, a: f64, b: f64, samples: u64, mut uniform: U) -> f64
+where
+    F: Fn(f64) -> f64,
+    U: FnMut() -> f64,
+{
+    if samples == 0 {
+        return 0.0;
+    }
+    let sum: f64 = (0..samples).map(|_| f(a + (b - a) * uniform())).sum();
+    (b - a) * sum / samples as f64
+}
+
+/// One row of a convergence report.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Convergence {
+    pub samples: u64,
+    pub estimate: f64,
+    pub abs_error: f64,
+    pub time: std::time::Duration,
+}
+
+/// Runs `estimator` once for every sample count and measures how far it is from `exact`.
+#[cfg(feature = "std")]
+pub fn convergence<E>(sample_counts: &[u64], exact: f64, mut estimator: E) -> Vec<Convergence>
+where
+    E: FnMut(u64) -> f64,
+{
+    sample_counts
+        .iter()
+        .map(|&samples| {
+            let mut estimate = 0.0;
+            let time = bench_once(|| estimate = estimator(samples));
+            Convergence {
+                samples,
+                estimate,
+                abs_error: (estimate - exact).abs(),
+                time,
+            }
+        })
+        .collect()
+}
+
+/// Renders results of [`convergence`] as a table.
+#[cfg(feature = "std")]
+pub fn convergence_report(rows: &[Convergence]) -> String {
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|r| {
+            vec![
+                r.samples.to_string(),
+                format!("{:.6}", r.estimate),
+                format!("{:.6}", r.abs_error),
+                format!("{:?}", r.time),
+            ]
+        })
+        .collect();
+    render_table(&["samples", "estimate", "abs error", "time"], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::rng::Rng;
+
+    fn uniform(seed: u64) -> impl FnMut() -> f64 {
+        let mut rng = Rng::new(seed);
+        move || rng.gen_f64()
+    }
+
+    #[test]
+    fn estimate_pi_test() {
+        assert_eq!(estimate_pi(0, uniform(1)), 0.0);
+        let pi = estimate_pi(200_000, uniform(42));
+        assert!((pi - PI).abs() < 0.02, "{pi}");
+    }
+
+    #[test]
+    fn integrate_test() {
+        let res = integrate(|x| x * x, 0.0, 3.0, 200_000, uniform(7));
+        assert!((res - 9.0).abs() < 0.1, "{res}");
+        let res = integrate(f64::sin, 0.0, PI, 200_000, uniform(7));
+        assert!((res - 2.0).abs() < 0.02, "{res}");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn convergence_report_test() {
+        let mut rng = Rng::new(3);
+        let rows = convergence(&[10, 1_000, 100_000], PI, |n| {
+            estimate_pi(n, || rng.gen_f64())
+        });
+        assert_eq!(rows.len(), 3);
+        assert!(rows[2].abs_error < 0.05);
+
+        let report = convergence_report(&rows);
+        assert_eq!(report.lines().count(), 7);
+        assert!(report.contains("| samples | estimate"));
+        assert!(report.contains("| 100000  |"));
+    }
+}
@@ -0,0 +1,145 @@
+use super::modular::mod_pow;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// NTT-friendly prime: `119 * 2^23 + 1`, so transforms of length up to `2^23` are possible.
+pub const MODULUS: u64 = 998_244_353;
+const PRIMITIVE_ROOT: u64 = 3;
+/// Longest supported transform: `2^23` is the largest power of two dividing `MODULUS - 1`.
+pub const MAX_LEN: usize = 1 << 23;
+
+/// In-place iterative radix-2 number theoretic transform (an FFT over integers modulo [`MODULUS`]).
+///
+/// `a.len()` must be a power of two, at most [`MAX_LEN`]. Values are reduced modulo
+/// [`MODULUS`] first. With `invert` set, computes the inverse transform.
+pub fn ntt(a: &mut [u64], invert: bool) {
+    let n = a.len();
+    assert!(n.is_power_of_two(), "length must be a power of two");
+    assert!(n <= MAX_LEN, "length {n} is above the maximum of 2^23");
+    a.iter_mut().for_each(|x| *x %= MODULUS);
+
+    // Bit-reversal permutation.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let mut w_len = mod_pow(PRIMITIVE_ROOT, (MODULUS - 1) / len as u64, MODULUS);
+        if invert {
+            w_len = mod_pow(w_len, MODULUS - 2, MODULUS);
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            let mut w = 1;
+            for (u, v) in lo.iter_mut().zip(hi) {
+                let t = *v * w % MODULUS;
+                (*u, *v) = ((*u + t) % MODULUS, (*u + MODULUS - t) % MODULUS);
+                w = w * w_len % MODULUS;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = mod_pow(n as u64, MODULUS - 2, MODULUS);
+        a.iter_mut().for_each(|x| *x = *x * n_inv % MODULUS);
+    }
+}
+
+/// Multiplies two polynomials given by their coefficients (lowest degree first) in `O(n log n)`.
+///
+/// All arithmetic is done modulo [`MODULUS`], so the result is exact as long as
+/// every coefficient of the product is below it.
+/// Panics if the product has more than [`MAX_LEN`] coefficients.
+pub fn multiply_polynomials(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let res_len = a.len() + b.len() - 1;
+    assert!(
+        res_len <= MAX_LEN,
+        "product has {res_len} coefficients, NTT modulo {MODULUS} supports at most 2^23"
+    );
+    let n = res_len.next_power_of_two();
+
+    let prepare = |p: &[u64]| {
+        let mut v = p.to_vec();
+        v.resize(n, 0);
+        ntt(&mut v, false);
+        v
+    };
+    let (mut fa, fb) = (prepare(a), prepare(b));
+    fa.iter_mut()
+        .zip(&fb)
+        .for_each(|(x, y)| *x = *x * y % MODULUS);
+    ntt(&mut fa, true);
+
+    fa.truncate(res_len);
+    fa
+}
+
+/// Schoolbook `O(n * m)` polynomial multiplication modulo [`MODULUS`], for comparison.
+pub fn multiply_polynomials_naive(a: &[u64], b: &[u64]) -> Vec<u64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut res = vec![0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            res[i + j] = (res[i + j] + x % MODULUS * (y % MODULUS)) % MODULUS;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ntt_roundtrip_test() {
+        let orig: Vec<u64> = (0..16).map(|x| x * x + 7).collect();
+        let mut a = orig.clone();
+        ntt(&mut a, false);
+        assert_ne!(a, orig);
+        ntt(&mut a, true);
+        assert_eq!(a, orig);
+
+        // Values above the modulus used to overflow in `*v * w`.
+        let mut a = [u64::MAX; 4];
+        ntt(&mut a, false);
+        ntt(&mut a, true);
+        assert_eq!(a, [u64::MAX % MODULUS; 4]);
+    }
+
+    #[test]
+    fn multiply_polynomials_test() {
+        // (1 + 2x + 3x^2) * (4 + 5x) = 4 + 13x + 22x^2 + 15x^3
+        assert_eq!(multiply_polynomials(&[1, 2, 3], &[4, 5]), [4, 13, 22, 15]);
+        assert_eq!(multiply_polynomials(&[7], &[6]), [42]);
+        assert!(multiply_polynomials(&[], &[1, 2]).is_empty());
+
+        let a: Vec<u64> = (0..300).map(|i| (i * 7919 + 13) % 1_000).collect();
+        let b: Vec<u64> = (0..77).map(|i| (i * 104_729 + 1) % MODULUS).collect();
+        assert_eq!(
+            multiply_polynomials(&a, &b),
+            multiply_polynomials_naive(&a, &b)
+        );
+
+        // Above `2^23` there's no root of unity of the right order.
+        let half = vec![0; MAX_LEN / 2 + 1];
+        let too_long = std::panic::catch_unwind(|| multiply_polynomials(&half, &half));
+        let message = *too_long.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("supports at most 2^23"));
+    }
+}
@@ -0,0 +1,155 @@
+use alloc::collections::BTreeMap;
+
+use super::modular::mod_pow;
+
+/// Small primes used both for trial division and as Miller–Rabin witnesses.
+const SMALL_PRIMES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+#[inline]
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Deterministic Miller–Rabin primality test for 64-bit integers.
+///
+/// The first twelve primes as witnesses are enough to cover the whole `u64` range.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in SMALL_PRIMES {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+
+    let (mut d, mut s) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+
+    'witness: for a in SMALL_PRIMES {
+        let mut x = mod_pow(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// Finds a non-trivial divisor of a composite `n` using Pollard's rho with Floyd's cycle detection.
+///
+/// `n` must be odd and composite, otherwise this may loop forever.
+fn pollard_rho(n: u64) -> u64 {
+    // Each failed run (gcd == n) restarts with a different polynomial `x^2 + c`.
+    for c in 1.. {
+        let f = |x| (mul_mod(x, x, n) + c) % n;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!("some `c` always yields a divisor of a composite number")
+}
+
+fn collect_factors(n: u64, factors: &mut BTreeMap<u64, u32>) {
+    if n == 1 {
+        return;
+    }
+    if is_prime(n) {
+        *factors.entry(n).or_insert(0) += 1;
+        return;
+    }
+    let d = pollard_rho(n);
+    collect_factors(d, factors);
+    collect_factors(n / d, factors);
+}
+
+/// Returns the prime factorization of `n` as a map from prime to its exponent.
+///
+/// Small factors are removed with trial division, the rest is split with Pollard's rho.
+/// `0` and `1` have no prime factors, so the map is empty for them.
+pub fn factorize(mut n: u64) -> BTreeMap<u64, u32> {
+    let mut factors = BTreeMap::new();
+    if n == 0 {
+        return factors;
+    }
+
+    for p in SMALL_PRIMES {
+        while n.is_multiple_of(p) {
+            *factors.entry(p).or_insert(0) += 1;
+            n /= p;
+        }
+    }
+    collect_factors(n, &mut factors);
+
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_prime_test() {
+        let primes: Vec<u64> = (0..50).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+        assert!(is_prime(998_244_353));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        // Strong pseudoprime to bases 2, 3, 5 and 7.
+        assert!(!is_prime(3_215_031_751));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn factorize_test() {
+        assert!(factorize(0).is_empty());
+        assert!(factorize(1).is_empty());
+        assert_eq!(factorize(360), BTreeMap::from([(2, 3), (3, 2), (5, 1)]));
+        assert_eq!(factorize(97), BTreeMap::from([(97, 1)]));
+        assert_eq!(
+            factorize(1_000_000_007 * 998_244_353),
+            BTreeMap::from([(998_244_353, 1), (1_000_000_007, 1)])
+        );
+        assert_eq!(
+            factorize(u64::MAX),
+            BTreeMap::from([
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65_537, 1),
+                (6_700_417, 1)
+            ])
+        );
+
+        for n in [2 * 2 * 41 * 41 * 43, 600_851_475_143, 4_294_967_297 * 3] {
+            let product: u64 = factorize(n).iter().map(|(p, &e)| p.pow(e)).product();
+            assert_eq!(product, n);
+        }
+    }
+}
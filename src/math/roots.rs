@@ -0,0 +1,258 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt;
+
+use crate::error::AlgoError;
+use crate::formatting::render_table;
+use crate::trace::{Event, NoTrace, Tracer};
+
+/// Newton's method stops once a step is smaller than this.
+pub const NEWTON_TOLERANCE: f64 = 1e-12;
+/// Upper bound on iterations for both methods, so a bad input can't loop forever.
+pub const MAX_ITERATIONS: u32 = 1_000;
+
+/// Approximate root together with the number of iterations it took to find.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Root {
+    pub x: f64,
+    pub iterations: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RootError {
+    /// `f(lo)` and `f(hi)` have the same sign, so bisection can't guarantee a root in between.
+    NoSignChange { lo: f64, hi: f64 },
+    /// Newton's method hit a point where the derivative is zero.
+    ZeroDerivative { x: f64 },
+    /// Didn't converge within [`MAX_ITERATIONS`].
+    NoConvergence { last: f64 },
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSignChange { lo, hi } => {
+                write!(f, "function has the same sign at {lo} and {hi}")
+            }
+            Self::ZeroDerivative { x } => write!(f, "derivative is zero at {x}"),
+            Self::NoConvergence { last } => write!(
+                f,
+                "no convergence after {MAX_ITERATIONS} iterations, last guess {last}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for RootError {}
+
+impl From<RootError> for AlgoError {
+    fn from(err: RootError) -> Self {
+        Self::InvalidInput(err.to_string())
+    }
+}
+
+/// Finds a root of `f` in `[lo, hi]` by repeatedly halving the interval.
+///
+/// Converges linearly: every iteration gains one bit of precision.
+/// Stops once the interval is narrower than `eps`.
+pub fn bisect<F>(f: F, lo: f64, hi: f64, eps: f64) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+{
+    bisect_traced(f, lo, hi, eps, &mut NoTrace)
+}
+
+/// Same as [`bisect`], reporting the midpoint of every iteration to `tracer`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(f, tracer))
+)]
+pub fn bisect_traced<F, T>(
+    f: F,
+    mut lo: f64,
+    mut hi: f64,
+    eps: f64,
+    tracer: &mut T,
+) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+    T: Tracer + ?Sized,
+{
+    let (mut f_lo, f_hi) = (f(lo), f(hi));
+    if f_lo == 0.0 {
+        return Ok(Root {
+            x: lo,
+            iterations: 0,
+        });
+    }
+    if f_hi == 0.0 {
+        return Ok(Root {
+            x: hi,
+            iterations: 0,
+        });
+    }
+    if f_lo.signum() == f_hi.signum() {
+        return Err(RootError::NoSignChange { lo, hi });
+    }
+
+    for iterations in 1..=MAX_ITERATIONS {
+        let mid = lo + (hi - lo) / 2.0;
+        let f_mid = f(mid);
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iteration = iterations, x = mid, "bisected");
+        tracer.record(Event::Iteration {
+            index: iterations,
+            value: mid,
+        });
+        if f_mid == 0.0 || (hi - lo) / 2.0 < eps {
+            return Ok(Root { x: mid, iterations });
+        }
+        if f_mid.signum() == f_lo.signum() {
+            (lo, f_lo) = (mid, f_mid);
+        } else {
+            hi = mid;
+        }
+    }
+
+    Err(RootError::NoConvergence {
+        last: lo + (hi - lo) / 2.0,
+    })
+}
+
+/// Finds a root of `f` starting from `x0`, using its derivative `df`.
+///
+/// Converges quadratically near a simple root (the number of correct digits roughly doubles
+/// each step), but may diverge with a bad starting point.
+pub fn newton<F, D>(f: F, df: D, x0: f64) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    newton_traced(f, df, x0, &mut NoTrace)
+}
+
+/// Same as [`newton`], reporting the estimate after every step to `tracer`.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(f, df, tracer))
+)]
+pub fn newton_traced<F, D, T>(f: F, df: D, x0: f64, tracer: &mut T) -> Result<Root, RootError>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+    T: Tracer + ?Sized,
+{
+    let mut x = x0;
+    for iterations in 1..=MAX_ITERATIONS {
+        let slope = df(x);
+        if slope == 0.0 {
+            return Err(RootError::ZeroDerivative { x });
+        }
+        let step = f(x) / slope;
+        x -= step;
+        #[cfg(feature = "tracing")]
+        tracing::trace!(iteration = iterations, x, step, "newton step");
+        tracer.record(Event::Iteration {
+            index: iterations,
+            value: x,
+        });
+        if !x.is_finite() {
+            break;
+        }
+        if step.abs() < NEWTON_TOLERANCE {
+            return Ok(Root { x, iterations });
+        }
+    }
+    Err(RootError::NoConvergence { last: x })
+}
+
+/// Renders named results as a table, so convergence speed of different methods can be compared.
+pub fn iterations_report(results: &[(&str, Root)]) -> String {
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .map(|(name, root)| {
+            vec![
+                name.to_string(),
+                format!("{:.10}", root.x),
+                root.iterations.to_string(),
+            ]
+        })
+        .collect();
+    render_table(&["method", "root", "iterations"], &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bisect_test() {
+        let f = |x: f64| x * x - 2.0;
+        let root = bisect(f, 0.0, 2.0, 1e-10).unwrap();
+        assert!((root.x - 2f64.sqrt()).abs() < 1e-9);
+        assert!(root.iterations > 30);
+
+        assert_eq!(
+            bisect(f, 0.0, 1.0, 1e-10),
+            Err(RootError::NoSignChange { lo: 0.0, hi: 1.0 })
+        );
+        assert_eq!(bisect(|x| x, 0.0, 1.0, 1e-10).map(|r| r.x), Ok(0.0));
+    }
+
+    #[test]
+    fn newton_test() {
+        let root = newton(|x| x * x - 2.0, |x| 2.0 * x, 1.0).unwrap();
+        assert!((root.x - 2f64.sqrt()).abs() < 1e-12);
+        assert!(root.iterations < 10);
+
+        assert_eq!(
+            newton(|x| x * x - 2.0, |x| 2.0 * x, 0.0),
+            Err(RootError::ZeroDerivative { x: 0.0 })
+        );
+        // x^2 + 1 has no real roots.
+        assert!(matches!(
+            newton(|x| x * x + 1.0, |x| 2.0 * x, 0.5),
+            Err(RootError::NoConvergence { .. })
+        ));
+        let err = AlgoError::from(RootError::ZeroDerivative { x: 0.0 });
+        assert!(matches!(err, AlgoError::InvalidInput(_)));
+    }
+
+    #[test]
+    fn iterations_report_test() {
+        let f = |x: f64| x.cos() - x;
+        let by_bisection = bisect(f, 0.0, 1.0, 1e-12).unwrap();
+        let by_newton = newton(f, |x| -x.sin() - 1.0, 1.0).unwrap();
+        assert!(by_newton.iterations < by_bisection.iterations);
+
+        let report = iterations_report(&[("bisection", by_bisection), ("newton", by_newton)]);
+        assert!(report.contains("| bisection | 0.7390851332 |"));
+        assert!(report.contains("| newton    | 0.7390851332 |"));
+    }
+
+    #[test]
+    fn traced_test() {
+        use crate::trace::Recorder;
+
+        let mut recorder = Recorder::new();
+        let root = newton_traced(|x| x * x - 2.0, |x| 2.0 * x, 1.0, &mut recorder).unwrap();
+        assert_eq!(recorder.events().len() as u32, root.iterations);
+        assert_eq!(
+            recorder.events()[0],
+            Event::Iteration {
+                index: 1,
+                value: 1.5
+            }
+        );
+
+        let mut recorder = Recorder::new();
+        let root = bisect_traced(|x| x - 0.3, 0.0, 1.0, 1e-6, &mut recorder).unwrap();
+        assert_eq!(recorder.events().len() as u32, root.iterations);
+        assert!(recorder
+            .explain()
+            .starts_with("1. iteration 1, estimate 0.5\n"));
+    }
+}
@@ -0,0 +1,153 @@
+/// One-pass accumulator for count, mean, variance, min and max using Welford's algorithm.
+///
+/// Needs `O(1)` memory and doesn't suffer from the catastrophic cancellation
+/// of the naive `E[x^2] - E[x]^2` formula.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunningStats {
+    count: u64,
+    mean: f64,
+    m2: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for RunningStats {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+}
+
+impl RunningStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, x: f64) {
+        self.count += 1;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+        self.min = self.min.min(x);
+        self.max = self.max.max(x);
+    }
+
+    /// Combines two accumulators as if all values were pushed into one (Chan et al.).
+    pub fn merge(&mut self, other: &RunningStats) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = *other;
+            return;
+        }
+        let count = self.count + other.count;
+        let delta = other.mean - self.mean;
+        self.mean += delta * other.count as f64 / count as f64;
+        self.m2 += other.m2 + delta * delta * (self.count * other.count) as f64 / count as f64;
+        self.count = count;
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.mean)
+    }
+
+    /// Population variance (divides by `n`).
+    pub fn variance(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.m2 / self.count as f64)
+    }
+
+    /// Sample variance (divides by `n - 1`), needs at least two values.
+    pub fn sample_variance(&self) -> Option<f64> {
+        (self.count > 1).then(|| self.m2 / (self.count - 1) as f64)
+    }
+
+    /// Population standard deviation.
+    #[cfg(feature = "std")]
+    pub fn std_dev(&self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    pub fn min(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.min)
+    }
+
+    pub fn max(&self) -> Option<f64> {
+        (self.count > 0).then_some(self.max)
+    }
+}
+
+impl Extend<f64> for RunningStats {
+    fn extend<I: IntoIterator<Item = f64>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|x| self.push(x));
+    }
+}
+
+impl FromIterator<f64> for RunningStats {
+    fn from_iter<I: IntoIterator<Item = f64>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn running_stats_test() {
+        let empty = RunningStats::new();
+        assert_eq!(empty.count(), 0);
+        assert_eq!(empty.mean(), None);
+        assert_eq!(empty.min(), None);
+
+        let stats: RunningStats = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]
+            .into_iter()
+            .collect();
+        assert_eq!(stats.count(), 8);
+        assert_eq!(stats.mean(), Some(5.0));
+        assert_eq!(stats.variance(), Some(4.0));
+        #[cfg(feature = "std")]
+        assert_eq!(stats.std_dev(), Some(2.0));
+        assert_eq!(stats.sample_variance(), Some(32.0 / 7.0));
+        assert_eq!((stats.min(), stats.max()), (Some(2.0), Some(9.0)));
+    }
+
+    #[test]
+    fn running_stats_is_stable_test() {
+        // The naive formula loses all precision with a large offset like this.
+        let offset = 1e9;
+        let stats: RunningStats = [4.0, 7.0, 13.0, 16.0].iter().map(|x| x + offset).collect();
+        assert_eq!(stats.variance(), Some(22.5));
+    }
+
+    #[test]
+    fn running_stats_merge_test() {
+        let values: Vec<f64> = (0..100).map(|x| (x * x % 17) as f64).collect();
+        let all: RunningStats = values.iter().copied().collect();
+
+        let mut left: RunningStats = values[..30].iter().copied().collect();
+        left.merge(&values[30..].iter().copied().collect());
+        assert_eq!(left.count(), all.count());
+        assert!((left.mean().unwrap() - all.mean().unwrap()).abs() < 1e-12);
+        assert!((left.variance().unwrap() - all.variance().unwrap()).abs() < 1e-9);
+        assert_eq!((left.min(), left.max()), (all.min(), all.max()));
+
+        let mut empty = RunningStats::new();
+        empty.merge(&all);
+        assert_eq!(empty, all);
+    }
+}
@@ -0,0 +1,33 @@
+pub mod distance;
+pub mod kmeans;
+pub mod knn;
+pub mod naive_bayes;
+pub mod recommend;
+pub mod regression;
+
+/// `f64` functions that need `std`, backed by `libm` in `no_std` builds.
+mod float {
+    pub fn square(x: f64) -> f64 {
+        x * x
+    }
+
+    #[cfg(feature = "std")]
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    #[cfg(feature = "std")]
+    pub fn ln(x: f64) -> f64 {
+        x.ln()
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub fn ln(x: f64) -> f64 {
+        libm::log(x)
+    }
+}
@@ -0,0 +1,148 @@
+use core::hash::Hash;
+
+use super::float::{sqrt, square};
+use crate::collections::HashSet;
+
+/// Straight-line distance between two points.
+pub fn euclidean(a: &[f64], b: &[f64]) -> f64 {
+    sqrt(a.iter().zip(b).map(|(x, y)| square(x - y)).sum())
+}
+
+/// Sum of absolute differences, a.k.a. taxicab distance.
+pub fn manhattan(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| (x - y).abs()).sum()
+}
+
+/// Cosine of the angle between two vectors, from `-1` (opposite) to `1` (same direction).
+///
+/// Ignores magnitudes, so it compares "tastes" rather than absolute values.
+/// Returns `0` if either vector is all zeroes.
+pub fn cosine_similarity(a: &[f64], b: &[f64]) -> f64 {
+    let dot: f64 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f64]| sqrt(v.iter().map(|x| x * x).sum::<f64>());
+    let (na, nb) = (norm(a), norm(b));
+    if na == 0.0 || nb == 0.0 {
+        return 0.0;
+    }
+    dot / (na * nb)
+}
+
+/// Number of positions at which the elements differ.
+///
+/// If lengths differ, each extra element counts as a mismatch.
+pub fn hamming<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let mismatches = a.iter().zip(b).filter(|(x, y)| x != y).count();
+    mismatches + a.len().abs_diff(b.len())
+}
+
+/// Jaccard index: size of the intersection divided by size of the union, from `0` to `1`.
+///
+/// Two empty sets are considered identical.
+pub fn jaccard<T: Eq + Hash>(a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f64 / union as f64
+}
+
+/// Distance between two values of type `T`, where smaller means more alike.
+pub trait Metric<T: ?Sized> {
+    fn distance(&self, a: &T, b: &T) -> f64;
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Manhattan;
+
+/// Cosine distance, i.e. `1 - cosine_similarity`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cosine;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Hamming;
+
+/// Jaccard distance, i.e. `1 - jaccard`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Jaccard;
+
+impl Metric<[f64]> for Euclidean {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        euclidean(a, b)
+    }
+}
+
+impl Metric<[f64]> for Manhattan {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        manhattan(a, b)
+    }
+}
+
+impl Metric<[f64]> for Cosine {
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        1.0 - cosine_similarity(a, b)
+    }
+}
+
+impl<T: PartialEq> Metric<[T]> for Hamming {
+    fn distance(&self, a: &[T], b: &[T]) -> f64 {
+        hamming(a, b) as f64
+    }
+}
+
+impl<T: Eq + Hash> Metric<HashSet<T>> for Jaccard {
+    fn distance(&self, a: &HashSet<T>, b: &HashSet<T>) -> f64 {
+        1.0 - jaccard(a, b)
+    }
+}
+
+/// Any plain function or closure over feature vectors can be used as a metric.
+impl<F> Metric<[f64]> for F
+where
+    F: Fn(&[f64], &[f64]) -> f64,
+{
+    fn distance(&self, a: &[f64], b: &[f64]) -> f64 {
+        self(a, b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vector_distances_test() {
+        let (a, b) = ([0.0, 0.0], [3.0, 4.0]);
+        assert_eq!(euclidean(&a, &b), 5.0);
+        assert_eq!(manhattan(&a, &b), 7.0);
+        assert_eq!(Euclidean.distance(&a, &b), 5.0);
+        assert_eq!(Manhattan.distance(&b, &a), 7.0);
+    }
+
+    #[test]
+    fn cosine_similarity_test() {
+        assert!((cosine_similarity(&[1.0, 2.0], &[2.0, 4.0]) - 1.0).abs() < 1e-12);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[-1.0, 0.0]), -1.0);
+        assert_eq!(cosine_similarity(&[0.0, 0.0], &[1.0, 1.0]), 0.0);
+        assert_eq!(Cosine.distance(&[1.0, 0.0][..], &[0.0, 1.0][..]), 1.0);
+    }
+
+    #[test]
+    fn hamming_test() {
+        assert_eq!(hamming(b"karolin", b"kathrin"), 3);
+        assert_eq!(hamming(&[1, 2], &[1, 2, 3]), 1);
+        assert_eq!(Hamming.distance(&['a'][..], &['b'][..]), 1.0);
+    }
+
+    #[test]
+    fn jaccard_test() {
+        let a = HashSet::from([1, 2, 3]);
+        let b = HashSet::from([2, 3, 4, 5]);
+        assert_eq!(jaccard(&a, &b), 0.4);
+        assert_eq!(Jaccard.distance(&a, &b), 0.6);
+        assert_eq!(jaccard::<i32>(&HashSet::new(), &HashSet::new()), 1.0);
+    }
+}
@@ -0,0 +1,201 @@
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use super::float::square;
+use crate::error::AlgoError;
+use crate::rng::Rng;
+
+/// Result of [`kmeans`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KMeans {
+    pub centroids: Vec<Vec<f64>>,
+    /// Index of the centroid each point belongs to.
+    pub assignments: Vec<usize>,
+    /// Sum of squared distances from points to their centroids, one value per iteration.
+    pub inertia: Vec<f64>,
+}
+
+fn squared_distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| square(x - y)).sum()
+}
+
+/// Index of the closest centroid and squared distance to it.
+fn closest(point: &[f64], centroids: &[Vec<f64>]) -> (usize, f64) {
+    centroids
+        .iter()
+        .map(|c| squared_distance(point, c))
+        .enumerate()
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap_or((0, 0.0))
+}
+
+/// Closest centroid for every point. This is the expensive step, so it runs
+/// on all cores with the `parallel` feature.
+fn assign<'a>(
+    points: &'a [Vec<f64>],
+    centroids: &'a [Vec<f64>],
+) -> impl Iterator<Item = (usize, f64)> + 'a {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        let closest: Vec<_> = points.par_iter().map(|p| closest(p, centroids)).collect();
+        closest.into_iter()
+    }
+    #[cfg(not(feature = "parallel"))]
+    points.iter().map(|p| closest(p, centroids))
+}
+
+/// k-means++ seeding: every next centroid is picked with probability
+/// proportional to its squared distance from the already chosen ones.
+fn init_centroids(points: &[Vec<f64>], k: usize, rng: &mut Rng) -> Vec<Vec<f64>> {
+    let mut centroids = vec![points[rng.gen_range(0..points.len())].clone()];
+    while centroids.len() < k {
+        let weights: Vec<f64> = points.iter().map(|p| closest(p, &centroids).1).collect();
+        let total: f64 = weights.iter().sum();
+        if total == 0.0 {
+            // All remaining points coincide with centroids, any of them will do.
+            centroids.push(points[rng.gen_range(0..points.len())].clone());
+            continue;
+        }
+        let mut target = rng.gen_f64() * total;
+        let idx = weights
+            .iter()
+            .position(|&w| {
+                target -= w;
+                target < 0.0
+            })
+            .unwrap_or(points.len() - 1);
+        centroids.push(points[idx].clone());
+    }
+    centroids
+}
+
+/// Groups points into `k` clusters using Lloyd's algorithm with k-means++ initialization.
+///
+/// Stops when assignments stop changing or after `max_iters` iterations.
+/// Fails if `k` is zero, there are fewer than `k` points or points have different dimensions.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(points, rng), fields(points = points.len()))
+)]
+pub fn kmeans(
+    points: &[Vec<f64>],
+    k: usize,
+    max_iters: usize,
+    rng: &mut Rng,
+) -> Result<KMeans, AlgoError> {
+    if k == 0 {
+        return Err(AlgoError::invalid_input("k must be positive"));
+    }
+    if points.len() < k {
+        let reason = format!("need at least {k} points, got {}", points.len());
+        return Err(AlgoError::InvalidInput(reason));
+    }
+    if points.iter().any(|p| p.len() != points[0].len()) {
+        return Err(AlgoError::invalid_input("points have different dimensions"));
+    }
+    let dims = points[0].len();
+    let mut centroids = init_centroids(points, k, rng);
+    let mut assignments = vec![usize::MAX; points.len()];
+    let mut inertia = Vec::new();
+
+    for _iteration in 0..max_iters {
+        let mut changed = false;
+        let mut total = 0.0;
+        for ((idx, dist), assignment) in assign(points, &centroids).zip(&mut assignments) {
+            changed |= *assignment != idx;
+            *assignment = idx;
+            total += dist;
+        }
+        inertia.push(total);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            iteration = _iteration,
+            inertia = total,
+            changed,
+            "assigned points"
+        );
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![vec![0.0; dims]; k];
+        let mut counts = vec![0usize; k];
+        for (point, &a) in points.iter().zip(&assignments) {
+            sums[a].iter_mut().zip(point).for_each(|(s, x)| *s += x);
+            counts[a] += 1;
+        }
+        #[cfg(feature = "tracing")]
+        tracing::trace!(sizes = ?counts, "cluster sizes");
+        // An empty cluster keeps its old centroid.
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            if count > 0 {
+                *centroid = sum.into_iter().map(|s| s / count as f64).collect();
+            }
+        }
+    }
+
+    Ok(KMeans {
+        centroids,
+        assignments,
+        inertia,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::formatting::sparkline;
+
+    fn blobs(rng: &mut Rng) -> Vec<Vec<f64>> {
+        let centers = [(0.0, 0.0), (10.0, 10.0), (0.0, 10.0)];
+        centers
+            .iter()
+            .flat_map(|&(cx, cy)| (0..30).map(move |_| (cx, cy)))
+            .map(|(cx, cy)| vec![cx + rng.gen_range(-1.0..1.0), cy + rng.gen_range(-1.0..1.0)])
+            .collect()
+    }
+
+    #[test]
+    fn kmeans_test() {
+        let mut rng = Rng::new(5);
+        let points = blobs(&mut rng);
+        let res = kmeans(&points, 3, 100, &mut rng).unwrap();
+
+        assert_eq!(res.centroids.len(), 3);
+        assert_eq!(res.assignments.len(), points.len());
+        // Points from the same blob end up in the same cluster.
+        for blob in res.assignments.chunks(30) {
+            assert!(blob.iter().all(|&a| a == blob[0]));
+        }
+        let mut clusters: Vec<usize> = res.assignments.chunks(30).map(|b| b[0]).collect();
+        clusters.sort_unstable();
+        assert_eq!(clusters, [0, 1, 2]);
+
+        assert!(res.inertia.windows(2).all(|w| w[1] <= w[0]));
+        #[cfg(feature = "std")]
+        assert_eq!(sparkline(&res.inertia).chars().count(), res.inertia.len());
+    }
+
+    #[test]
+    fn kmeans_invalid_input_test() {
+        let mut rng = Rng::new(1);
+        let points = vec![vec![1.0], vec![2.0]];
+        assert_eq!(
+            kmeans(&points, 0, 10, &mut rng),
+            Err(AlgoError::invalid_input("k must be positive"))
+        );
+        assert_eq!(
+            kmeans(&points, 3, 10, &mut rng),
+            Err(AlgoError::invalid_input("need at least 3 points, got 2"))
+        );
+        let ragged = vec![vec![1.0], vec![2.0, 3.0]];
+        assert!(kmeans(&ragged, 1, 10, &mut rng).is_err());
+
+        let same = vec![vec![1.0]; 4];
+        let res = kmeans(&same, 2, 10, &mut rng).unwrap();
+        assert_eq!(res.inertia.last(), Some(&0.0));
+    }
+}
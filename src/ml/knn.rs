@@ -0,0 +1,210 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use super::distance::{Euclidean, Metric};
+use crate::collections::HashMap;
+
+/// Indices of the `k` training points closest to `point`, nearest first.
+fn nearest<M>(features: &[Vec<f64>], point: &[f64], k: usize, metric: &M) -> Vec<usize>
+where
+    M: Metric<[f64]>,
+{
+    let mut by_distance: Vec<(f64, usize)> = features
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (metric.distance(f, point), i))
+        .collect();
+    by_distance.sort_by(|a, b| a.0.total_cmp(&b.0));
+    by_distance.into_iter().take(k).map(|(_, i)| i).collect()
+}
+
+/// Classifies a point by a majority vote among its `k` nearest neighbors.
+#[derive(Debug, Clone)]
+pub struct KnnClassifier<L, M = Euclidean> {
+    k: usize,
+    metric: M,
+    features: Vec<Vec<f64>>,
+    labels: Vec<L>,
+}
+
+impl<L: Clone + Eq + Hash> KnnClassifier<L> {
+    /// Creates a classifier using euclidean distance. Panics if `k` is zero.
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0, "k must be positive");
+        Self {
+            k,
+            metric: Euclidean,
+            features: Vec::new(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<L: Clone + Eq + Hash, M: Metric<[f64]>> KnnClassifier<L, M> {
+    pub fn with_metric<N: Metric<[f64]>>(self, metric: N) -> KnnClassifier<L, N> {
+        KnnClassifier {
+            k: self.k,
+            metric,
+            features: self.features,
+            labels: self.labels,
+        }
+    }
+
+    /// Remembers the training data. Panics if there isn't exactly one label per feature vector.
+    pub fn fit(&mut self, features: Vec<Vec<f64>>, labels: Vec<L>) {
+        assert_eq!(features.len(), labels.len(), "every sample needs a label");
+        (self.features, self.labels) = (features, labels);
+    }
+
+    /// Predicts the label of `point`, or `None` if the classifier wasn't fitted.
+    ///
+    /// Ties are broken in favor of the label with the closest neighbor.
+    pub fn predict(&self, point: &[f64]) -> Option<L> {
+        let neighbors = nearest(&self.features, point, self.k, &self.metric);
+        let mut votes: HashMap<&L, (usize, usize)> = HashMap::new();
+        for (rank, &i) in neighbors.iter().enumerate() {
+            votes.entry(&self.labels[i]).or_insert((0, rank)).0 += 1;
+        }
+        votes
+            .into_iter()
+            .max_by(|(_, (c1, r1)), (_, (c2, r2))| c1.cmp(c2).then(r2.cmp(r1)))
+            .map(|(label, _)| label.clone())
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<L, M> KnnClassifier<L, M>
+where
+    L: Clone + Eq + Hash + Send + Sync,
+    M: Metric<[f64]> + Sync,
+{
+    /// Predicts labels for many points at once, spreading the work over all cores.
+    pub fn par_predict(&self, points: &[Vec<f64>]) -> Vec<Option<L>> {
+        use rayon::prelude::*;
+        points.par_iter().map(|p| self.predict(p)).collect()
+    }
+}
+
+/// Predicts a value for a point by averaging the targets of its `k` nearest neighbors.
+#[derive(Debug, Clone)]
+pub struct KnnRegressor<M = Euclidean> {
+    k: usize,
+    metric: M,
+    features: Vec<Vec<f64>>,
+    targets: Vec<f64>,
+}
+
+impl KnnRegressor {
+    /// Creates a regressor using euclidean distance. Panics if `k` is zero.
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0, "k must be positive");
+        Self {
+            k,
+            metric: Euclidean,
+            features: Vec::new(),
+            targets: Vec::new(),
+        }
+    }
+}
+
+impl<M: Metric<[f64]>> KnnRegressor<M> {
+    pub fn with_metric<N: Metric<[f64]>>(self, metric: N) -> KnnRegressor<N> {
+        KnnRegressor {
+            k: self.k,
+            metric,
+            features: self.features,
+            targets: self.targets,
+        }
+    }
+
+    /// Remembers the training data. Panics if there isn't exactly one target per feature vector.
+    pub fn fit(&mut self, features: Vec<Vec<f64>>, targets: Vec<f64>) {
+        assert_eq!(features.len(), targets.len(), "every sample needs a target");
+        (self.features, self.targets) = (features, targets);
+    }
+
+    /// Predicts the value at `point`, or `None` if the regressor wasn't fitted.
+    pub fn predict(&self, point: &[f64]) -> Option<f64> {
+        let neighbors = nearest(&self.features, point, self.k, &self.metric);
+        if neighbors.is_empty() {
+            return None;
+        }
+        let sum: f64 = neighbors.iter().map(|&i| self.targets[i]).sum();
+        Some(sum / neighbors.len() as f64)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<M: Metric<[f64]> + Sync> KnnRegressor<M> {
+    /// Predicts values for many points at once, spreading the work over all cores.
+    pub fn par_predict(&self, points: &[Vec<f64>]) -> Vec<Option<f64>> {
+        use rayon::prelude::*;
+        points.par_iter().map(|p| self.predict(p)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ml::distance::Manhattan;
+
+    #[test]
+    fn knn_classifier_test() {
+        let mut knn = KnnClassifier::new(3);
+        assert_eq!(knn.predict(&[0.0, 0.0]), None);
+
+        let features = vec![
+            vec![0.0, 0.0],
+            vec![0.0, 1.0],
+            vec![1.0, 0.0],
+            vec![5.0, 5.0],
+            vec![5.0, 6.0],
+            vec![6.0, 5.0],
+        ];
+        knn.fit(features, vec!["a", "a", "a", "b", "b", "b"]);
+        assert_eq!(knn.predict(&[0.5, 0.5]), Some("a"));
+        assert_eq!(knn.predict(&[5.5, 5.5]), Some("b"));
+        assert_eq!(knn.predict(&[3.0, 2.0]), Some("a"));
+    }
+
+    #[test]
+    fn knn_classifier_tie_test() {
+        let mut knn = KnnClassifier::new(2).with_metric(Manhattan);
+        knn.fit(vec![vec![0.0], vec![3.0]], vec!['x', 'y']);
+        assert_eq!(knn.predict(&[1.0]), Some('x'));
+        assert_eq!(knn.predict(&[2.0]), Some('y'));
+    }
+
+    #[test]
+    fn knn_regressor_test() {
+        let mut knn = KnnRegressor::new(2);
+        assert_eq!(knn.predict(&[1.0]), None);
+        knn.fit(
+            vec![vec![0.0], vec![1.0], vec![2.0], vec![10.0]],
+            vec![0.0, 10.0, 20.0, 100.0],
+        );
+        assert_eq!(knn.predict(&[0.4]), Some(5.0));
+        assert_eq!(knn.predict(&[9.0]), Some(60.0));
+
+        let by_first = |a: &[f64], b: &[f64]| (a[0] - b[0]).abs();
+        let mut knn = KnnRegressor::new(1).with_metric(by_first);
+        knn.fit(vec![vec![0.0, 100.0], vec![5.0, 0.0]], vec![1.0, 2.0]);
+        assert_eq!(knn.predict(&[1.0, 0.0]), Some(1.0));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn knn_par_predict_test() {
+        let features: Vec<Vec<f64>> = (0..100).map(|i| vec![i as f64]).collect();
+        let labels: Vec<bool> = (0..100).map(|i| i >= 50).collect();
+        let mut knn = KnnClassifier::new(3);
+        knn.fit(features.clone(), labels);
+
+        let sequential: Vec<_> = features.iter().map(|p| knn.predict(p)).collect();
+        assert_eq!(knn.par_predict(&features), sequential);
+
+        let mut knn = KnnRegressor::new(2);
+        knn.fit(features.clone(), (0..100).map(|i| i as f64).collect());
+        assert_eq!(knn.par_predict(&[vec![10.4]]), [Some(10.5)]);
+    }
+}
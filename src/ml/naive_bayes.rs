@@ -0,0 +1,149 @@
+use alloc::format;
+use alloc::string::String;
+use core::hash::Hash;
+
+use super::float::ln;
+use crate::collections::{HashMap, HashSet};
+use crate::error::AlgoError;
+use crate::shorthands::counter;
+
+/// Splits text into lowercase words, dropping punctuation.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Multinomial naive Bayes classifier over word counts.
+#[derive(Debug, Clone)]
+pub struct NaiveBayes<L> {
+    /// Laplace (additive) smoothing, keeps unseen words from zeroing out the whole probability.
+    alpha: f64,
+    docs_per_label: HashMap<L, usize>,
+    word_counts: HashMap<L, HashMap<String, usize>>,
+    total_words: HashMap<L, usize>,
+    vocabulary: HashSet<String>,
+}
+
+impl<L: Clone + Eq + Hash> Default for NaiveBayes<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L: Clone + Eq + Hash> NaiveBayes<L> {
+    /// Creates a classifier with add-one smoothing.
+    pub fn new() -> Self {
+        Self {
+            alpha: 1.0,
+            docs_per_label: HashMap::new(),
+            word_counts: HashMap::new(),
+            total_words: HashMap::new(),
+            vocabulary: HashSet::new(),
+        }
+    }
+
+    /// Creates a classifier with the given smoothing, which must be positive and finite.
+    pub fn with_alpha(alpha: f64) -> Result<Self, AlgoError> {
+        if !(alpha > 0.0 && alpha.is_finite()) {
+            let reason = format!("alpha must be positive and finite, got {alpha}");
+            return Err(AlgoError::InvalidInput(reason));
+        }
+        Ok(Self {
+            alpha,
+            ..Self::new()
+        })
+    }
+
+    /// Adds one labeled document to the model. Can be called any number of times.
+    pub fn train(&mut self, text: &str, label: L) {
+        *self.docs_per_label.entry(label.clone()).or_insert(0) += 1;
+        let words = self.word_counts.entry(label.clone()).or_default();
+        let total = self.total_words.entry(label).or_insert(0);
+        for (word, count) in counter(tokenize(text)) {
+            *total += count;
+            self.vocabulary.insert(word.clone());
+            *words.entry(word).or_insert(0) += count;
+        }
+    }
+
+    /// Log-probability (up to a shared constant) of `text` belonging to each label.
+    pub fn log_scores(&self, text: &str) -> HashMap<L, f64> {
+        let words = counter(tokenize(text));
+        let total_docs: usize = self.docs_per_label.values().sum();
+        let vocab = self.vocabulary.len() as f64;
+
+        self.docs_per_label
+            .iter()
+            .map(|(label, &docs)| {
+                let prior = ln(docs as f64 / total_docs as f64);
+                let counts = &self.word_counts[label];
+                let denom = self.total_words[label] as f64 + self.alpha * vocab;
+                let likelihood: f64 = words
+                    .iter()
+                    .map(|(w, &n)| {
+                        let seen = counts.get(w).copied().unwrap_or(0) as f64;
+                        n as f64 * ln((seen + self.alpha) / denom)
+                    })
+                    .sum();
+                (label.clone(), prior + likelihood)
+            })
+            .collect()
+    }
+
+    /// Most probable label for `text`, or `None` if nothing was trained yet.
+    pub fn predict(&self, text: &str) -> Option<L> {
+        self.log_scores(text)
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(label, _)| label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_test() {
+        let words: Vec<String> = tokenize("Hello, WORLD!! it's 2024").collect();
+        assert_eq!(words, ["hello", "world", "it", "s", "2024"]);
+    }
+
+    #[test]
+    fn naive_bayes_test() {
+        let mut nb = NaiveBayes::new();
+        assert_eq!(nb.predict("anything"), None);
+
+        nb.train("win money now", "spam");
+        nb.train("cheap money offer, win big", "spam");
+        nb.train("meeting at noon tomorrow", "ham");
+        nb.train("lunch tomorrow with the team", "ham");
+        nb.train("project meeting notes", "ham");
+
+        assert_eq!(nb.predict("win cheap money"), Some("spam"));
+        assert_eq!(nb.predict("team meeting tomorrow"), Some("ham"));
+        // Only unseen words: falls back to priors, and there are more ham documents.
+        assert_eq!(nb.predict("zebra"), Some("ham"));
+
+        let scores = nb.log_scores("money");
+        assert!(scores["spam"] > scores["ham"]);
+    }
+
+    #[test]
+    fn naive_bayes_alpha_test() {
+        let mut nb = NaiveBayes::with_alpha(0.5).unwrap();
+        nb.train("win money", "spam");
+        nb.train("team meeting", "ham");
+        assert_eq!(nb.predict("money"), Some("spam"));
+
+        for alpha in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let err = NaiveBayes::<&str>::with_alpha(alpha).unwrap_err();
+            assert!(matches!(err, AlgoError::InvalidInput(_)), "{alpha}");
+        }
+        assert_eq!(
+            NaiveBayes::<&str>::with_alpha(0.0).unwrap_err(),
+            AlgoError::invalid_input("alpha must be positive and finite, got 0")
+        );
+    }
+}
@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use super::distance::cosine_similarity;
+use crate::collections::{HashMap, HashSet};
+
+/// Ratings a single user gave to items.
+pub type Ratings<I> = HashMap<I, f64>;
+
+/// Cosine similarity of two users' tastes, treating unrated items as zero.
+pub fn user_similarity<I: Eq + Hash>(a: &Ratings<I>, b: &Ratings<I>) -> f64 {
+    let items: HashSet<&I> = a.keys().chain(b.keys()).collect();
+    let vector = |r: &Ratings<I>| -> Vec<f64> {
+        items
+            .iter()
+            .map(|i| r.get(*i).copied().unwrap_or(0.0))
+            .collect()
+    };
+    cosine_similarity(&vector(a), &vector(b))
+}
+
+/// The `k` users from `all_ratings` most similar to `user_ratings`, most similar first.
+pub fn nearest_users<'a, U, I>(
+    user_ratings: &Ratings<I>,
+    all_ratings: &'a HashMap<U, Ratings<I>>,
+    k: usize,
+) -> Vec<(&'a U, f64)>
+where
+    I: Eq + Hash,
+{
+    let mut similar: Vec<(&U, f64)> = all_ratings
+        .iter()
+        .map(|(user, ratings)| (user, user_similarity(user_ratings, ratings)))
+        .collect();
+    similar.sort_by(|a, b| b.1.total_cmp(&a.1));
+    similar.truncate(k);
+    similar
+}
+
+/// Predicts ratings for items the user hasn't rated yet, highest first.
+///
+/// Finds the `k` most similar users by cosine similarity and averages their ratings,
+/// weighted by how similar each of them is. Users with non-positive similarity are ignored.
+pub fn recommend<U, I>(
+    user_ratings: &Ratings<I>,
+    all_ratings: &HashMap<U, Ratings<I>>,
+    k: usize,
+) -> Vec<(I, f64)>
+where
+    U: Eq + Hash,
+    I: Clone + Eq + Hash,
+{
+    let mut weighted: HashMap<&I, (f64, f64)> = HashMap::new();
+    for (user, similarity) in nearest_users(user_ratings, all_ratings, k) {
+        if similarity <= 0.0 {
+            continue;
+        }
+        for (item, &rating) in &all_ratings[user] {
+            if user_ratings.contains_key(item) {
+                continue;
+            }
+            let (sum, weights) = weighted.entry(item).or_insert((0.0, 0.0));
+            *sum += similarity * rating;
+            *weights += similarity;
+        }
+    }
+
+    let mut predictions: Vec<(I, f64)> = weighted
+        .into_iter()
+        .map(|(item, (sum, weights))| (item.clone(), sum / weights))
+        .collect();
+    predictions.sort_by(|a, b| b.1.total_cmp(&a.1));
+    predictions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ratings<const N: usize>(r: [(&'static str, f64); N]) -> Ratings<&'static str> {
+        HashMap::from(r)
+    }
+
+    #[test]
+    fn user_similarity_test() {
+        let a = ratings([("comedy", 5.0), ("drama", 1.0)]);
+        let b = ratings([("comedy", 4.0), ("drama", 1.0)]);
+        let c = ratings([("horror", 5.0)]);
+        assert!(user_similarity(&a, &b) > 0.99);
+        assert_eq!(user_similarity(&a, &c), 0.0);
+    }
+
+    #[test]
+    fn recommend_test() {
+        let all = HashMap::from([
+            (
+                "justin",
+                ratings([
+                    ("pitch perfect", 5.0),
+                    ("avengers", 1.0),
+                    ("mean girls", 5.0),
+                ]),
+            ),
+            (
+                "morpheus",
+                ratings([("pitch perfect", 1.0), ("avengers", 5.0), ("die hard", 5.0)]),
+            ),
+            (
+                "joe",
+                ratings([
+                    ("pitch perfect", 4.0),
+                    ("avengers", 2.0),
+                    ("mean girls", 3.0),
+                ]),
+            ),
+        ]);
+        let priyanka = ratings([("pitch perfect", 5.0), ("avengers", 1.0)]);
+
+        let nearest = nearest_users(&priyanka, &all, 2);
+        assert_eq!(
+            nearest.iter().map(|(u, _)| **u).collect::<Vec<_>>(),
+            ["joe", "justin"]
+        );
+
+        let recs = recommend(&priyanka, &all, 2);
+        assert_eq!(recs.len(), 1);
+        assert_eq!(recs[0].0, "mean girls");
+        assert!(recs[0].1 > 3.0 && recs[0].1 < 5.0);
+
+        // With every user as a neighbor "die hard" shows up too. Morpheus is the only one
+        // who rated it, so the weighted average is just his rating.
+        let recs: HashMap<_, _> = recommend(&priyanka, &all, 3).into_iter().collect();
+        assert_eq!(recs.len(), 2);
+        assert!((recs["die hard"] - 5.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,187 @@
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+use super::float::square;
+use crate::error::AlgoError;
+
+/// Fitted linear model `y = intercept + coefficients · x`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegression {
+    pub intercept: f64,
+    pub coefficients: Vec<f64>,
+    /// Coefficient of determination on the training data, `1` being a perfect fit.
+    pub r_squared: f64,
+}
+
+/// Solves `a * x = b` with Gaussian elimination and partial pivoting.
+///
+/// Returns `None` if the system is (numerically) singular.
+fn solve(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col].clone();
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (x, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
+impl LinearRegression {
+    /// Fits `y = a + b * x` with ordinary least squares.
+    ///
+    /// Fails if there are fewer than two points or all `x` are equal.
+    pub fn fit_simple(xs: &[f64], ys: &[f64]) -> Result<Self, AlgoError> {
+        let features: Vec<Vec<f64>> = xs.iter().map(|&x| vec![x]).collect();
+        Self::fit(&features, ys)
+    }
+
+    /// Fits a multiple linear regression by solving the normal equations `XᵀX β = Xᵀy`.
+    ///
+    /// Fine for a handful of features; for many features or ill-conditioned data
+    /// a QR decomposition would be more accurate.
+    /// Fails if sizes don't match, there's too little data or features are linearly dependent.
+    pub fn fit(features: &[Vec<f64>], targets: &[f64]) -> Result<Self, AlgoError> {
+        let n = features.len();
+        let dims = features.first().map_or(0, Vec::len);
+        if n != targets.len() {
+            let reason = format!("{n} samples but {} targets", targets.len());
+            return Err(AlgoError::InvalidInput(reason));
+        }
+        if n <= dims {
+            let reason = format!("need more than {dims} samples, got {n}");
+            return Err(AlgoError::InvalidInput(reason));
+        }
+        if features.iter().any(|f| f.len() != dims) {
+            return Err(AlgoError::invalid_input(
+                "samples have different dimensions",
+            ));
+        }
+
+        // Design matrix rows are `[1, x1, x2, ...]`, the leading one accounts for the intercept.
+        let row = |f: &Vec<f64>| {
+            core::iter::once(1.0)
+                .chain(f.iter().copied())
+                .collect::<Vec<_>>()
+        };
+        let design: Vec<Vec<f64>> = features.iter().map(row).collect();
+
+        let mut xtx = vec![vec![0.0; dims + 1]; dims + 1];
+        let mut xty = vec![0.0; dims + 1];
+        for (x, &y) in design.iter().zip(targets) {
+            for i in 0..=dims {
+                for j in 0..=dims {
+                    xtx[i][j] += x[i] * x[j];
+                }
+                xty[i] += x[i] * y;
+            }
+        }
+        let beta = solve(xtx, xty)
+            .ok_or_else(|| AlgoError::invalid_input("features are linearly dependent"))?;
+
+        let mut model = Self {
+            intercept: beta[0],
+            coefficients: beta[1..].to_vec(),
+            r_squared: 0.0,
+        };
+        model.r_squared = model.score(features, targets);
+        Ok(model)
+    }
+
+    pub fn predict(&self, x: &[f64]) -> f64 {
+        let dot: f64 = self.coefficients.iter().zip(x).map(|(c, v)| c * v).sum();
+        self.intercept + dot
+    }
+
+    /// R² of the model on the given data.
+    pub fn score(&self, features: &[Vec<f64>], targets: &[f64]) -> f64 {
+        let mean = targets.iter().sum::<f64>() / targets.len() as f64;
+        let ss_tot: f64 = targets.iter().map(|y| square(y - mean)).sum();
+        let ss_res: f64 = features
+            .iter()
+            .zip(targets)
+            .map(|(x, y)| square(y - self.predict(x)))
+            .sum();
+        if ss_tot == 0.0 {
+            return if ss_res == 0.0 { 1.0 } else { 0.0 };
+        }
+        1.0 - ss_res / ss_tot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn simple_regression_test() {
+        let xs = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let ys: Vec<f64> = xs.iter().map(|x| 3.0 + 2.0 * x).collect();
+        let model = LinearRegression::fit_simple(&xs, &ys).unwrap();
+        assert!(approx(model.intercept, 3.0));
+        assert!(approx(model.coefficients[0], 2.0));
+        assert!(approx(model.r_squared, 1.0));
+        assert!(approx(model.predict(&[10.0]), 23.0));
+
+        let noisy =
+            LinearRegression::fit_simple(&[1.0, 2.0, 3.0, 4.0], &[1.0, 3.0, 2.0, 4.0]).unwrap();
+        assert!(approx(noisy.coefficients[0], 0.8));
+        assert!(approx(noisy.intercept, 0.5));
+        assert!(approx(noisy.r_squared, 0.64));
+
+        assert_eq!(
+            LinearRegression::fit_simple(&[1.0, 1.0], &[1.0, 2.0]),
+            Err(AlgoError::invalid_input("features are linearly dependent"))
+        );
+        assert_eq!(
+            LinearRegression::fit_simple(&[1.0], &[1.0]),
+            Err(AlgoError::invalid_input("need more than 1 samples, got 1"))
+        );
+    }
+
+    #[test]
+    fn multiple_regression_test() {
+        let features: Vec<Vec<f64>> = (0..20)
+            .map(|i| vec![i as f64, ((i * 7) % 5) as f64])
+            .collect();
+        let targets: Vec<f64> = features
+            .iter()
+            .map(|f| 1.0 + 2.0 * f[0] - 3.0 * f[1])
+            .collect();
+        let model = LinearRegression::fit(&features, &targets).unwrap();
+        assert!(approx(model.intercept, 1.0));
+        assert!(approx(model.coefficients[0], 2.0));
+        assert!(approx(model.coefficients[1], -3.0));
+        assert!(approx(model.r_squared, 1.0));
+
+        // The second feature is a copy of the first.
+        let dependent: Vec<Vec<f64>> = (0..5).map(|i| vec![i as f64, i as f64]).collect();
+        assert!(LinearRegression::fit(&dependent, &[0.0; 5]).is_err());
+        assert_eq!(
+            LinearRegression::fit(&features, &targets[1..]),
+            Err(AlgoError::invalid_input("20 samples but 19 targets"))
+        );
+    }
+}
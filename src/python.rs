@@ -0,0 +1,93 @@
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::error::AlgoError;
+use crate::graphs::{as_borrowed, shortest_path_solver, shortest_path_solvers, NodeGraph};
+
+/// Missing nodes become `KeyError`, like a failed dict lookup would in Python.
+fn to_py_err(e: AlgoError) -> PyErr {
+    match e {
+        AlgoError::MissingNode(_) => PyKeyError::new_err(e.to_string()),
+        _ => PyValueError::new_err(e.to_string()),
+    }
+}
+
+/// Shortest path from `start` to `finish` as a `(nodes, cost)` tuple.
+///
+/// `graph` is a dict of dicts, `{node: {neighbor: weight}}`, same as in the book.
+#[pyfunction]
+#[pyo3(signature = (graph, start, finish, algorithm = "dijkstra"))]
+fn shortest_path(
+    graph: NodeGraph<String, i32>,
+    start: String,
+    finish: String,
+    algorithm: &str,
+) -> PyResult<(Vec<String>, i32)> {
+    let solver = shortest_path_solver::<String>(algorithm)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown algorithm '{algorithm}'")))?;
+    let graph = as_borrowed(&graph);
+    let path = solver.find(&graph, &start, &finish).map_err(to_py_err)?;
+    Ok((path.nodes.into_iter().cloned().collect(), path.cost))
+}
+
+/// Cost of the shortest path from `start` to `finish`.
+#[pyfunction]
+fn dijkstra(graph: NodeGraph<String, i32>, start: String, finish: String) -> PyResult<i32> {
+    shortest_path(graph, start, finish, "dijkstra").map(|(_, cost)| cost)
+}
+
+/// Names accepted by the `algorithm` argument of `shortest_path`.
+#[pyfunction]
+fn algorithms() -> Vec<&'static str> {
+    shortest_path_solvers::<String>()
+        .iter()
+        .map(|s| s.name())
+        .collect()
+}
+
+#[pymodule]
+fn algo_examples(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(shortest_path, m)?)?;
+    m.add_function(wrap_pyfunction!(dijkstra, m)?)?;
+    m.add_function(wrap_pyfunction!(algorithms, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn python_module_test() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "algo_examples").unwrap();
+            algo_examples(&module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("algo", module).unwrap();
+
+            let code = c"
+graph = {'start': {'a': 6, 'b': 2}, 'a': {'fin': 1}, 'b': {'a': 3, 'fin': 5}, 'fin': {}}
+assert algo.dijkstra(graph, 'start', 'fin') == 6
+assert algo.shortest_path(graph, 'start', 'fin') == (['start', 'b', 'a', 'fin'], 6)
+assert algo.algorithms() == ['dijkstra']
+
+try:
+    algo.dijkstra(graph, 'start', 'nowhere')
+except KeyError:
+    pass
+else:
+    raise AssertionError('missing node should raise KeyError')
+
+try:
+    algo.shortest_path(graph, 'start', 'fin', algorithm='bogo')
+except ValueError as e:
+    assert 'bogo' in str(e)
+else:
+    raise AssertionError('unknown algorithm should raise ValueError')
+";
+            py.run(code, None, Some(&locals)).unwrap();
+        });
+    }
+}
@@ -0,0 +1,168 @@
+use core::ops::{Range, RangeInclusive};
+
+/// Small, fast, seedable pseudo-random number generator (xorshift64*).
+///
+/// Not suitable for cryptography, but the same seed always produces the same sequence,
+/// which keeps random inputs for tests and benchmarks reproducible.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+/// Mixes the seed with one round of splitmix64, so that similar seeds
+/// (and zero, which is a fixed point of xorshift) produce unrelated sequences.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let state = splitmix64(seed);
+        Self {
+            state: if state == 0 { 1 } else { state },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state >> 12;
+        self.state ^= self.state << 25;
+        self.state ^= self.state >> 27;
+        self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Uniformly distributed `f64` in `[0, 1)`.
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn gen_bool(&mut self, probability: f64) -> bool {
+        self.gen_f64() < probability
+    }
+
+    /// Uniformly distributed value in `range`. Panics if the range is empty.
+    pub fn gen_range<T, R: SampleRange<T>>(&mut self, range: R) -> T {
+        range.sample(self)
+    }
+
+    /// Fills `dest` with random bytes.
+    pub fn fill(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Shuffles a slice in place using the Fisher–Yates algorithm.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.gen_range(0..=i));
+        }
+    }
+
+    /// Unbiased integer in `0..bound` using Lemire's multiply-and-reject method.
+    fn below(&mut self, bound: u64) -> u64 {
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let m = self.next_u64() as u128 * bound as u128;
+            if (m as u64) >= threshold {
+                return (m >> 64) as u64;
+            }
+        }
+    }
+}
+
+/// Ranges [`Rng::gen_range`] can sample from.
+pub trait SampleRange<T> {
+    fn sample(self, rng: &mut Rng) -> T;
+}
+
+macro_rules! impl_sample_range {
+    ($($t:ty => $u:ty),*) => {$(
+        impl SampleRange<$t> for Range<$t> {
+            fn sample(self, rng: &mut Rng) -> $t {
+                assert!(self.start < self.end, "cannot sample from an empty range");
+                let span = self.end.wrapping_sub(self.start) as $u as u64;
+                self.start.wrapping_add(rng.below(span) as $t)
+            }
+        }
+
+        impl SampleRange<$t> for RangeInclusive<$t> {
+            fn sample(self, rng: &mut Rng) -> $t {
+                let (start, end) = self.into_inner();
+                assert!(start <= end, "cannot sample from an empty range");
+                let span = end.wrapping_sub(start) as $u as u64;
+                let offset = match span.checked_add(1) {
+                    Some(bound) => rng.below(bound),
+                    None => rng.next_u64(),
+                };
+                start.wrapping_add(offset as $t)
+            }
+        }
+    )*};
+}
+
+impl_sample_range!(u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize);
+
+impl SampleRange<f64> for Range<f64> {
+    fn sample(self, rng: &mut Rng) -> f64 {
+        assert!(self.start < self.end, "cannot sample from an empty range");
+        self.start + (self.end - self.start) * rng.gen_f64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng_is_deterministic_test() {
+        let (mut a, mut b) = (Rng::new(42), Rng::new(42));
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+    }
+
+    #[test]
+    fn gen_range_test() {
+        let mut rng = Rng::new(7);
+        let mut seen = [false; 6];
+        for _ in 0..1_000 {
+            let x = rng.gen_range(-3..3);
+            assert!((-3..3).contains(&x));
+            seen[(x + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        assert_eq!(rng.gen_range(5..=5u8), 5);
+        let _ = rng.gen_range(i64::MIN..=i64::MAX);
+        let _ = rng.gen_range(0..=u64::MAX);
+        for _ in 0..100 {
+            let f = rng.gen_range(1.5..2.5);
+            assert!((1.5..2.5).contains(&f));
+            assert!((0.0..1.0).contains(&rng.gen_f64()));
+        }
+    }
+
+    #[test]
+    fn fill_and_shuffle_test() {
+        let mut rng = Rng::new(1);
+        let mut bytes = [0u8; 13];
+        rng.fill(&mut bytes);
+        assert!(bytes.iter().any(|&b| b != 0));
+
+        let mut items: Vec<u32> = (0..50).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
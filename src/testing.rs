@@ -0,0 +1,6 @@
+pub mod cross_check;
+pub mod generators;
+#[cfg(feature = "std")]
+pub mod snapshot;
+#[cfg(feature = "proptest")]
+pub mod strategies;
@@ -0,0 +1,356 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::rng::Rng;
+
+/// Upper bound on accepted shrinking steps, so a bad [`Shrink`] impl can't loop forever.
+const MAX_SHRINKS: usize = 1_000;
+
+/// Produces smaller versions of a value, used to minimize counterexamples.
+pub trait Shrink: Sized {
+    /// Candidates that are "simpler" than `self`, most aggressive first.
+    fn shrink(&self) -> Vec<Self>;
+}
+
+macro_rules! impl_shrink_int {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let x = *self;
+                let mut candidates = vec![0, x / 2, x - x.signum()];
+                candidates.dedup();
+                candidates.retain(|&c| c != x);
+                candidates
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_shrink_uint {
+    ($($t:ty),*) => {$(
+        impl Shrink for $t {
+            fn shrink(&self) -> Vec<Self> {
+                let x = *self;
+                let mut candidates = vec![0, x / 2, x.saturating_sub(1)];
+                candidates.dedup();
+                candidates.retain(|&c| c != x);
+                candidates
+            }
+        }
+    )*};
+}
+
+impl_shrink_int!(i8, i16, i32, i64, isize);
+impl_shrink_uint!(u8, u16, u32, u64, usize);
+
+impl Shrink for bool {
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            vec![false]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Shrink for char {
+    fn shrink(&self) -> Vec<Self> {
+        if *self == 'a' {
+            Vec::new()
+        } else {
+            vec!['a']
+        }
+    }
+}
+
+/// Drops halves first, then single elements, then shrinks elements in place.
+impl<T: Shrink + Clone> Shrink for Vec<T> {
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        if self.len() > 1 {
+            let mid = self.len() / 2;
+            candidates.push(self[mid..].to_vec());
+            candidates.push(self[..mid].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut smaller = self.clone();
+            smaller.remove(i);
+            candidates.push(smaller);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for simpler in item.shrink() {
+                let mut candidate = self.clone();
+                candidate[i] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+impl Shrink for String {
+    fn shrink(&self) -> Vec<Self> {
+        let chars: Vec<char> = self.chars().collect();
+        chars.shrink().into_iter().map(String::from_iter).collect()
+    }
+}
+
+impl<A: Shrink + Clone, B: Shrink + Clone> Shrink for (A, B) {
+    fn shrink(&self) -> Vec<Self> {
+        let firsts = self.0.shrink().into_iter().map(|a| (a, self.1.clone()));
+        let seconds = self.1.shrink().into_iter().map(|b| (self.0.clone(), b));
+        firsts.chain(seconds).collect()
+    }
+}
+
+/// Drops nodes (together with edges leading to them), then edges, then shrinks weights.
+///
+/// Nodes and edges are visited in sorted order, so shrinking doesn't depend on hash order.
+#[cfg(feature = "graphs")]
+impl<K, V> Shrink for crate::graphs::NodeGraph<K, V>
+where
+    K: Clone + Ord + core::hash::Hash,
+    V: Shrink + Clone,
+{
+    fn shrink(&self) -> Vec<Self> {
+        let mut nodes: Vec<&K> = self.keys().collect();
+        nodes.sort();
+        let mut edges: Vec<(&K, &K, &V)> = self
+            .iter()
+            .flat_map(|(from, neighbors)| neighbors.iter().map(move |(to, w)| (from, to, w)))
+            .collect();
+        edges.sort_by(|a, b| (a.0, a.1).cmp(&(b.0, b.1)));
+
+        let mut candidates = Vec::new();
+        for node in nodes {
+            let mut smaller = self.clone();
+            smaller.remove(node);
+            smaller.values_mut().for_each(|neighbors| {
+                neighbors.remove(node);
+            });
+            candidates.push(smaller);
+        }
+        for (from, to, weight) in edges {
+            let mut smaller = self.clone();
+            smaller.get_mut(from).unwrap().remove(to);
+            candidates.push(smaller);
+
+            for simpler in weight.shrink() {
+                let mut candidate = self.clone();
+                candidate.get_mut(from).unwrap().insert(to.clone(), simpler);
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
+/// Input on which two implementations disagreed, after shrinking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Divergence<I, O> {
+    /// Index of the generated case that first diverged.
+    pub case: usize,
+    /// Number of successful shrinking steps from the generated input.
+    pub shrinks: usize,
+    pub input: I,
+    pub left: O,
+    pub right: O,
+}
+
+impl<I: fmt::Debug, O: fmt::Debug> fmt::Display for Divergence<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "implementations diverged on case {} (shrunk {} times)",
+            self.case, self.shrinks
+        )?;
+        writeln!(f, "input: {:?}", self.input)?;
+        writeln!(f, "left:  {:?}", self.left)?;
+        write!(f, "right: {:?}", self.right)
+    }
+}
+
+/// Runs `left` and `right` on `cases` inputs made by `generate`, comparing their outputs.
+///
+/// The first input they disagree on is shrunk while they keep disagreeing,
+/// and returned with both outputs. The same `seed` always produces the same inputs.
+pub fn cross_check<I, O, G, L, R>(
+    cases: usize,
+    seed: u64,
+    mut generate: G,
+    left: L,
+    right: R,
+) -> Result<(), Divergence<I, O>>
+where
+    I: Shrink,
+    O: PartialEq,
+    G: FnMut(&mut Rng) -> I,
+    L: Fn(&I) -> O,
+    R: Fn(&I) -> O,
+{
+    let diverges = |input: &I| {
+        let (l, r) = (left(input), right(input));
+        (l != r).then_some((l, r))
+    };
+
+    let mut rng = Rng::new(seed);
+    for case in 0..cases {
+        let mut input = generate(&mut rng);
+        let Some(mut outputs) = diverges(&input) else {
+            continue;
+        };
+
+        let mut shrinks = 0;
+        'shrinking: while shrinks < MAX_SHRINKS {
+            for candidate in input.shrink() {
+                if let Some(candidate_outputs) = diverges(&candidate) {
+                    (input, outputs) = (candidate, candidate_outputs);
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+
+        let (left, right) = outputs;
+        return Err(Divergence {
+            case,
+            shrinks,
+            input,
+            left,
+            right,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shrink_test() {
+        assert_eq!(10u32.shrink(), [0, 5, 9]);
+        assert_eq!((-3i32).shrink(), [0, -1, -2]);
+        assert!(0u8.shrink().is_empty());
+        let expected = [vec![], vec![0], vec![1], vec![2]];
+        assert_eq!(vec![3u8].shrink(), expected);
+        assert_eq!(vec![1, 2].shrink()[..2], [vec![2], vec![1]]);
+        assert_eq!(String::from("b").shrink(), ["", "a"]);
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn cross_check_agreeing_test() {
+        use crate::math::big_uint::BigUint;
+        use crate::math::ntt::{multiply_polynomials, multiply_polynomials_naive};
+        use crate::testing::generators::{array, Distribution};
+
+        let limbs = |rng: &mut Rng| {
+            let len = rng.gen_range(0..70);
+            (0..len).map(|_| rng.next_u32()).collect::<Vec<_>>()
+        };
+        let res = cross_check(
+            20,
+            1,
+            |rng| (limbs(rng), limbs(rng)),
+            |(a, b)| BigUint::from_limbs(a.clone()).mul_schoolbook(&BigUint::from_limbs(b.clone())),
+            |(a, b)| BigUint::from_limbs(a.clone()).mul_karatsuba(&BigUint::from_limbs(b.clone())),
+        );
+        assert_eq!(res, Ok(()));
+
+        let coefficients = |rng: &mut Rng| {
+            let len = rng.gen_range(0..40);
+            array(rng, len, &Distribution::Uniform(0..=1_000))
+                .into_iter()
+                .map(|x| x as u64)
+                .collect::<Vec<_>>()
+        };
+        let res = cross_check(
+            50,
+            2,
+            |rng| (coefficients(rng), coefficients(rng)),
+            |(a, b)| multiply_polynomials(a, b),
+            |(a, b)| multiply_polynomials_naive(a, b),
+        );
+        assert_eq!(res, Ok(()));
+    }
+
+    #[test]
+    fn cross_check_minimizes_test() {
+        // Forgets to count values above 100.
+        let buggy_sum = |items: &Vec<u32>| items.iter().filter(|&&x| x <= 100).sum::<u32>();
+        let sum = |items: &Vec<u32>| items.iter().sum::<u32>();
+        let generate = |rng: &mut Rng| {
+            let len = rng.gen_range(0..20);
+            (0..len).map(|_| rng.gen_range(0..1_000)).collect()
+        };
+
+        let divergence = cross_check(100, 3, generate, sum, buggy_sum).unwrap_err();
+        assert_eq!(divergence.input, [101]);
+        assert_eq!((divergence.left, divergence.right), (101, 0));
+        assert!(divergence.shrinks > 0);
+        assert!(divergence.to_string().contains("input: [101]"));
+    }
+
+    #[cfg(feature = "graphs")]
+    #[test]
+    fn cross_check_dijkstra_test() {
+        use crate::collections::HashMap;
+        use crate::graphs::dijkstra::dejkstras_alg;
+        use crate::graphs::{as_borrowed, NodeGraph};
+        use crate::testing::generators::graph;
+        use std::collections::BTreeMap;
+
+        /// Reference implementation, which handles negative weights.
+        fn bellman_ford(graph: &NodeGraph<String, i32>, start: &str) -> BTreeMap<String, i32> {
+            let mut costs = BTreeMap::from([(start.to_owned(), 0)]);
+            for _ in 0..graph.len() {
+                for (from, neighbors) in graph {
+                    let Some(&cost) = costs.get(from) else {
+                        continue;
+                    };
+                    for (to, &weight) in neighbors {
+                        if costs.get(to).is_none_or(|&c| cost + weight < c) {
+                            costs.insert(to.clone(), cost + weight);
+                        }
+                    }
+                }
+            }
+            costs
+        }
+        let dijkstra = |graph: &NodeGraph<String, i32>| {
+            let borrowed = as_borrowed(graph);
+            let start = "n0".to_owned();
+            let costs = graph.keys().filter_map(|to| {
+                let cost = dejkstras_alg(&borrowed, &start, to).ok()?;
+                Some((to.clone(), cost))
+            });
+            costs.collect::<BTreeMap<_, _>>()
+        };
+        let reference = |graph: &NodeGraph<String, i32>| {
+            if graph.contains_key("n0") {
+                bellman_ford(graph, "n0")
+            } else {
+                BTreeMap::new()
+            }
+        };
+
+        let positive = |rng: &mut Rng| graph(rng, 8, 0.3, 0..=9);
+        assert_eq!(cross_check(30, 4, positive, dijkstra, reference), Ok(()));
+
+        // Negative weights are what Dijkstra can't handle, the shrunk graph shows just that.
+        let negative = |rng: &mut Rng| graph(rng, 8, 0.3, -3..=9);
+        let divergence = cross_check(30, 4, negative, dijkstra, reference).unwrap_err();
+        let (n0, n7) = ("n0".to_owned(), "n7".to_owned());
+        let expected = NodeGraph::from([
+            (n0.clone(), HashMap::from([(n7.clone(), -1)])),
+            (n7.clone(), HashMap::new()),
+        ]);
+        assert_eq!(divergence.input, expected, "{divergence}");
+        assert_eq!(divergence.left, BTreeMap::new());
+        assert_eq!(divergence.right, BTreeMap::from([(n0, 0), (n7, -1)]));
+    }
+}
@@ -0,0 +1,190 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "graphs")]
+use crate::collections::HashMap;
+#[cfg(feature = "graphs")]
+use crate::graphs::NodeGraph;
+#[cfg(feature = "math")]
+use crate::math::big_uint::BigUint;
+use crate::rng::Rng;
+
+/// Shape of the values produced by [`array`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Distribution {
+    /// Independent values, uniformly distributed in the range.
+    Uniform(RangeInclusive<i64>),
+    /// `0, 1, 2, ...`
+    Sorted,
+    /// `len - 1, len - 2, ..., 0`
+    Reversed,
+    /// Sorted, then the given number of random pairs swapped.
+    NearlySorted { swaps: usize },
+    /// Values in `0..distinct`, so most of them repeat.
+    FewUnique { distinct: i64 },
+}
+
+/// Array of `len` integers shaped by `distribution`.
+pub fn array(rng: &mut Rng, len: usize, distribution: &Distribution) -> Vec<i64> {
+    let ascending = || (0..len as i64).collect::<Vec<_>>();
+    match distribution {
+        Distribution::Uniform(range) => (0..len).map(|_| rng.gen_range(range.clone())).collect(),
+        Distribution::Sorted => ascending(),
+        Distribution::Reversed => (0..len as i64).rev().collect(),
+        Distribution::NearlySorted { swaps } => {
+            let mut items = ascending();
+            if len > 1 {
+                for _ in 0..*swaps {
+                    items.swap(rng.gen_range(0..len), rng.gen_range(0..len));
+                }
+            }
+            items
+        }
+        Distribution::FewUnique { distinct } => {
+            (0..len).map(|_| rng.gen_range(0..*distinct)).collect()
+        }
+    }
+}
+
+/// Normally distributed floats, using the Box–Muller transform.
+#[cfg(feature = "std")]
+pub fn normal(rng: &mut Rng, len: usize, mean: f64, std_dev: f64) -> Vec<f64> {
+    (0..len)
+        .map(|_| {
+            // `1 - u` keeps the logarithm away from zero.
+            let (u1, u2) = (1.0 - rng.gen_f64(), rng.gen_f64());
+            let z = (-2.0 * u1.ln()).sqrt() * (core::f64::consts::TAU * u2).cos();
+            mean + std_dev * z
+        })
+        .collect()
+}
+
+const PLAIN: &[char] = &[
+    'a', 'b', 'z', 'A', 'Q', '0', '7', ' ', '-', '_', 'é', 'ß', 'ж',
+];
+const EMOJI: &[char] = &['😀', '🙏', '🌍', '🍎', '🍌', '🚀', '🛸', '✅', '✨', '🇺'];
+
+/// String of `len` characters, each an emoji with probability `emoji_ratio`.
+///
+/// The rest is a mix of ASCII and other one-column characters, which is what
+/// [`on_screen_len`](crate::formatting::on_screen_len) has to tell apart.
+pub fn emoji_string(rng: &mut Rng, len: usize, emoji_ratio: f64) -> String {
+    (0..len)
+        .map(|_| {
+            let pool = if rng.gen_bool(emoji_ratio) {
+                EMOJI
+            } else {
+                PLAIN
+            };
+            pool[rng.gen_range(0..pool.len())]
+        })
+        .collect()
+}
+
+/// Number made of `limbs` random base `2^32` digits.
+#[cfg(feature = "math")]
+pub fn big_uint(rng: &mut Rng, limbs: usize) -> BigUint {
+    BigUint::from_limbs((0..limbs).map(|_| rng.next_u32()).collect())
+}
+
+/// Directed graph with nodes `n0..n{nodes - 1}`.
+///
+/// Every other node is a neighbor with probability `density`, and edge weights
+/// are uniform in `weights`. Every node gets an entry, even without outgoing edges.
+#[cfg(feature = "graphs")]
+pub fn graph(
+    rng: &mut Rng,
+    nodes: usize,
+    density: f64,
+    weights: RangeInclusive<i32>,
+) -> NodeGraph<String, i32> {
+    let name = |i: usize| alloc::format!("n{i}");
+    let mut graph = NodeGraph::with_capacity(nodes);
+    for from in 0..nodes {
+        let mut neighbors = HashMap::new();
+        for to in (0..nodes).filter(|&to| to != from) {
+            if rng.gen_bool(density) {
+                neighbors.insert(name(to), rng.gen_range(weights.clone()));
+            }
+        }
+        graph.insert(name(from), neighbors);
+    }
+    graph
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_test() {
+        let mut rng = Rng::new(3);
+        let sorted = array(&mut rng, 5, &Distribution::Sorted);
+        assert_eq!(sorted, [0, 1, 2, 3, 4]);
+        assert_eq!(array(&mut rng, 3, &Distribution::Reversed), [2, 1, 0]);
+
+        let uniform = array(&mut rng, 100, &Distribution::Uniform(-5..=5));
+        assert!(uniform.iter().all(|x| (-5..=5).contains(x)));
+
+        let mut nearly = array(&mut rng, 50, &Distribution::NearlySorted { swaps: 3 });
+        let misplaced = nearly.iter().enumerate().filter(|&(i, &x)| x != i as i64);
+        assert!(misplaced.count() <= 6);
+        nearly.sort_unstable();
+        assert_eq!(nearly, array(&mut rng, 50, &Distribution::Sorted));
+
+        let few = array(&mut rng, 100, &Distribution::FewUnique { distinct: 3 });
+        assert!(few.iter().all(|x| (0..3).contains(x)));
+    }
+
+    #[cfg(all(feature = "std", feature = "math"))]
+    #[test]
+    fn normal_test() {
+        let values = normal(&mut Rng::new(5), 10_000, 10.0, 2.0);
+        let mut stats = crate::math::stats::RunningStats::new();
+        values.iter().for_each(|&v| stats.push(v));
+        assert!((stats.mean().unwrap() - 10.0).abs() < 0.1);
+        assert!((stats.std_dev().unwrap() - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn emoji_string_test() {
+        use crate::formatting::on_screen_len;
+
+        let mut rng = Rng::new(8);
+        let s = emoji_string(&mut rng, 40, 0.5);
+        let emoji = s.chars().filter(|c| EMOJI.contains(c)).count();
+        assert_eq!(s.chars().count(), 40);
+        assert_eq!(on_screen_len(&s), 40 + emoji);
+        assert!(emoji > 0 && emoji < 40);
+
+        let plain = emoji_string(&mut rng, 20, 0.0);
+        assert!(plain.chars().all(|c| PLAIN.contains(&c)));
+    }
+
+    #[cfg(feature = "math")]
+    #[test]
+    fn big_uint_test() {
+        let mut rng = Rng::new(2);
+        assert!(big_uint(&mut rng, 0).is_zero());
+        assert!(big_uint(&mut rng, 3) > BigUint::from(u64::MAX));
+    }
+
+    #[cfg(feature = "graphs")]
+    #[test]
+    fn graph_test() {
+        let mut rng = Rng::new(4);
+        let g = graph(&mut rng, 10, 1.0, 1..=1);
+        assert_eq!(g.len(), 10);
+        assert!(g.values().all(|neighbors| neighbors.len() == 9));
+        assert!(!g["n3"].contains_key("n3"));
+
+        let g = graph(&mut rng, 10, 0.0, 1..=1);
+        assert!(g.values().all(|neighbors| neighbors.is_empty()));
+
+        let g = graph(&mut rng, 30, 0.3, -2..=7);
+        let edges = g.values().flat_map(|neighbors| neighbors.values());
+        assert!(edges.clone().all(|w| (-2..=7).contains(w)));
+        assert!((100..400).contains(&edges.count()));
+    }
+}
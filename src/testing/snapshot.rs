@@ -0,0 +1,214 @@
+use std::path::Path;
+use std::{env, fs};
+
+use crate::formatting::on_screen_len;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// One line of a [`diff`], with line numbers in the expected and the actual text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change<'a> {
+    Same(usize, usize, &'a str),
+    Removed(usize, &'a str),
+    Added(usize, &'a str),
+}
+
+/// Line diff via the longest common subsequence of lines.
+fn changes<'a>(expected: &[&'a str], actual: &[&'a str]) -> Vec<Change<'a>> {
+    let (n, m) = (expected.len(), actual.len());
+    // `lcs[i][j]` is the LCS length of `expected[i..]` and `actual[j..]`.
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j, mut out) = (0, 0, Vec::new());
+    while i < n || j < m {
+        if i < n && j < m && expected[i] == actual[j] {
+            out.push(Change::Same(i, j, expected[i]));
+            (i, j) = (i + 1, j + 1);
+        } else if j == m || (i < n && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(Change::Removed(i, expected[i]));
+            i += 1;
+        } else {
+            out.push(Change::Added(j, actual[j]));
+            j += 1;
+        }
+    }
+    out
+}
+
+/// Renders a side-by-side diff, expected on the left and actual on the right.
+///
+/// Columns are padded by on-screen length, so lines with emoji stay aligned.
+/// Removed lines are red and added ones green if `color` is set.
+pub fn diff(expected: &str, actual: &str, color: bool) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let width = expected.iter().map(|l| on_screen_len(l)).max().unwrap_or(0);
+    let pad = |s: &str| format!("{s}{}", " ".repeat(width - on_screen_len(s)));
+    let paint = |line: String, code: &str| {
+        if color {
+            format!("{code}{line}{RESET}")
+        } else {
+            line
+        }
+    };
+
+    let rows = changes(&expected, &actual)
+        .into_iter()
+        .map(|change| match change {
+            Change::Same(i, j, line) => {
+                format!("  {:>3} {} | {:>3} {line}", i + 1, pad(line), j + 1)
+            }
+            Change::Removed(i, line) => paint(format!("- {:>3} {} |", i + 1, pad(line)), RED),
+            Change::Added(j, line) => {
+                paint(format!("+     {} | {:>3} {line}", pad(""), j + 1), GREEN)
+            }
+        });
+    rows.collect::<Vec<_>>().join("\n")
+}
+
+/// Whether snapshots should be (re)recorded, i.e. `UPDATE_SNAPSHOTS=1` is set.
+pub fn update_snapshots() -> bool {
+    env::var_os("UPDATE_SNAPSHOTS").is_some_and(|v| v == "1")
+}
+
+/// Compares `actual` with the golden file `<dir>/<name>.snap`.
+///
+/// With `update` set a missing or mismatching file is (over)written from `actual`.
+/// Otherwise panics if the file is missing, or with a [`diff`] if it doesn't match,
+/// colored unless `NO_COLOR` is set.
+pub fn assert_snapshot_in(dir: &Path, name: &str, actual: &str, update: bool) {
+    let path = dir.join(format!("{name}.snap"));
+
+    match fs::read_to_string(&path) {
+        Ok(expected) if expected == actual => {}
+        Ok(expected) if !update => {
+            let color = env::var_os("NO_COLOR").is_none();
+            panic!(
+                "snapshot '{name}' doesn't match {}\n{}\n\n\
+                 Run with UPDATE_SNAPSHOTS=1 to accept the new output.",
+                path.display(),
+                diff(&expected, actual, color)
+            );
+        }
+        Err(_) if !update => panic!(
+            "snapshot '{name}' is missing, expected {}\n\n\
+             Run with UPDATE_SNAPSHOTS=1 to record it.",
+            path.display()
+        ),
+        _ => {
+            let written = fs::create_dir_all(dir).and_then(|_| fs::write(&path, actual));
+            if let Err(e) = written {
+                panic!("can't write snapshot {}: {e}", path.display());
+            }
+        }
+    }
+}
+
+/// Compares a string with `snapshots/<name>.snap` in the calling crate, see
+/// [`assert_snapshot_in`](crate::testing::snapshot::assert_snapshot_in).
+/// Snapshots are recorded or updated when `UPDATE_SNAPSHOTS=1` is set.
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::testing::snapshot::assert_snapshot_in(
+            &::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("snapshots"),
+            $name,
+            ::core::convert::AsRef::<str>::as_ref(&$actual),
+            $crate::testing::snapshot::update_snapshots(),
+        )
+    };
+}
+
+/// Same as [`assert_snapshot!`], storing the pretty-printed `Debug` output of a value.
+#[macro_export]
+macro_rules! assert_debug_snapshot {
+    ($name:expr, $value:expr) => {
+        $crate::assert_snapshot!($name, format!("{:#?}", $value))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_test() {
+        let expected = "| ✅ | a |\nsame\nold";
+        let actual = "| ✅ | b |\nsame\nnew\nextra";
+        let expected_diff = "\
+-   1 | ✅ | a | |
++                |   1 | ✅ | b |
+    2 same       |   2 same
+-   3 old        |
++                |   3 new
++                |   4 extra";
+        assert_eq!(diff(expected, actual, false), expected_diff);
+        assert!(diff("a", "b", true).starts_with("\x1b[31m-   1 a |\x1b[0m"));
+        assert_eq!(
+            diff("x\ny", "x\ny", false),
+            "    1 x |   1 x\n    2 y |   2 y"
+        );
+    }
+
+    #[test]
+    fn assert_snapshot_in_test() {
+        let dir = env::temp_dir().join(format!("algo_examples_snapshots_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let missing = std::panic::catch_unwind(|| assert_snapshot_in(&dir, "table", "a\nb", false));
+        let message = *missing.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("snapshot 'table' is missing"));
+        assert!(!dir.join("table.snap").exists());
+
+        // Recorded when updating, then compared.
+        assert_snapshot_in(&dir, "table", "a\nb", true);
+        assert_eq!(fs::read_to_string(dir.join("table.snap")).unwrap(), "a\nb");
+        assert_snapshot_in(&dir, "table", "a\nb", false);
+
+        let mismatch =
+            std::panic::catch_unwind(|| assert_snapshot_in(&dir, "table", "a\nc", false));
+        let message = *mismatch.unwrap_err().downcast::<String>().unwrap();
+        assert!(message.contains("snapshot 'table' doesn't match"));
+        assert!(message.contains("UPDATE_SNAPSHOTS=1"));
+
+        assert_snapshot_in(&dir, "table", "a\nc", true);
+        assert_eq!(fs::read_to_string(dir.join("table.snap")).unwrap(), "a\nc");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn golden_snapshots_test() {
+        use crate::formatting::render_table;
+
+        let rows = vec![vec!["dijkstra", "✅", "6"], vec!["bellman-ford", "🚧"]];
+        crate::assert_snapshot!(
+            "render_table",
+            render_table(&["algorithm", "ok", "cost"], &rows)
+        );
+
+        #[cfg(feature = "graphs")]
+        {
+            use crate::graphs::dijkstra::dejkstras_path;
+            use crate::graphs::NodeGraph;
+            use crate::shorthands::new_h_map;
+
+            let mut graph = NodeGraph::new();
+            graph.insert("start", new_h_map([("a", 6), ("b", 2)]));
+            graph.insert("b", new_h_map([("a", 3), ("fin", 5)]));
+            graph.insert("a", new_h_map([("fin", 1)]));
+            graph.insert("fin", new_h_map([]));
+            crate::assert_debug_snapshot!("dijkstra_path", dejkstras_path(&graph, "start", "fin"));
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::ops::Range;
+#[cfg(feature = "graphs")]
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "math")]
+use proptest::arbitrary::Arbitrary;
+use proptest::prelude::*;
+
+use super::generators::{self, Distribution};
+#[cfg(feature = "graphs")]
+use crate::graphs::NodeGraph;
+#[cfg(feature = "math")]
+use crate::math::big_uint::BigUint;
+use crate::rng::Rng;
+
+/// Graphs from [`generators::graph`] with a node count in `nodes`.
+///
+/// Shrinks towards fewer nodes.
+#[cfg(feature = "graphs")]
+pub fn graph(
+    nodes: Range<usize>,
+    density: f64,
+    weights: RangeInclusive<i32>,
+) -> impl Strategy<Value = NodeGraph<String, i32>> {
+    (nodes, any::<u64>()).prop_map(move |(n, seed)| {
+        generators::graph(&mut Rng::new(seed), n, density, weights.clone())
+    })
+}
+
+/// Arrays from [`generators::array`] with a length in `len`.
+pub fn array(len: Range<usize>, distribution: Distribution) -> impl Strategy<Value = Vec<i64>> {
+    (len, any::<u64>())
+        .prop_map(move |(n, seed)| generators::array(&mut Rng::new(seed), n, &distribution))
+}
+
+/// Strings from [`generators::emoji_string`] with a length in `len`.
+pub fn emoji_string(len: Range<usize>, emoji_ratio: f64) -> impl Strategy<Value = String> {
+    (len, any::<u64>())
+        .prop_map(move |(n, seed)| generators::emoji_string(&mut Rng::new(seed), n, emoji_ratio))
+}
+
+/// Up to 80 limbs, which is enough to reach the Karatsuba code path.
+#[cfg(feature = "math")]
+impl Arbitrary for BigUint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        prop::collection::vec(any::<u32>(), 0..80)
+            .prop_map(BigUint::from_limbs)
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::formatting::on_screen_len;
+
+    proptest! {
+        #[cfg(feature = "graphs")]
+        #[test]
+        fn graph_strategy_test(g in graph(0..20, 0.5, 0..=9)) {
+            prop_assert!(g.len() < 20);
+            for neighbors in g.values() {
+                prop_assert!(neighbors.keys().all(|n| g.contains_key(n)));
+            }
+        }
+
+        #[test]
+        fn array_strategy_test(items in array(0..50, Distribution::Reversed)) {
+            prop_assert!(items.windows(2).all(|w| w[0] > w[1]));
+        }
+
+        #[test]
+        fn emoji_string_strategy_test(s in emoji_string(0..30, 1.0)) {
+            prop_assert_eq!(on_screen_len(&s), 2 * s.chars().count());
+        }
+
+        #[cfg(feature = "math")]
+        #[test]
+        fn big_uint_arbitrary_test(a: BigUint, b: BigUint) {
+            prop_assert_eq!(&(&a + &b) - &b, a);
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// One step an algorithm took, recorded for explanations and replays.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// Node was picked for processing, its cost is final.
+    Visit { node: String, cost: i64 },
+    /// Found a cheaper way to reach `to`. `old_cost` is `None` if it wasn't reached before.
+    Relax {
+        from: String,
+        to: String,
+        old_cost: Option<i64>,
+        new_cost: i64,
+    },
+    /// Edge was considered, but didn't improve on the known cost.
+    Keep {
+        from: String,
+        to: String,
+        cost: i64,
+        best: i64,
+    },
+    /// Iterative method produced a new estimate.
+    Iteration { index: u32, value: f64 },
+    /// Anything that doesn't fit the other variants.
+    Note(String),
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Visit { node, cost } => write!(f, "visited {node}, final cost {cost}"),
+            Self::Relax {
+                from,
+                to,
+                old_cost,
+                new_cost,
+            } => match old_cost {
+                Some(old) => write!(f, "relaxed edge {from}→{to}, cost {old}→{new_cost}"),
+                None => write!(f, "reached {to} via {from}, cost ∞→{new_cost}"),
+            },
+            Self::Keep {
+                from,
+                to,
+                cost,
+                best,
+            } => write!(f, "kept {to}: {cost} via {from} is not better than {best}"),
+            Self::Iteration { index, value } => write!(f, "iteration {index}, estimate {value}"),
+            Self::Note(note) => f.write_str(note),
+        }
+    }
+}
+
+/// Receives steps from algorithms that support tracing.
+pub trait Tracer {
+    fn record(&mut self, event: Event);
+
+    /// Whether events are wanted at all. Algorithms skip building them if not.
+    #[inline]
+    fn enabled(&self) -> bool {
+        true
+    }
+}
+
+/// Discards everything. Used by the untraced versions of algorithms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoTrace;
+
+impl Tracer for NoTrace {
+    #[inline]
+    fn record(&mut self, _: Event) {}
+
+    #[inline]
+    fn enabled(&self) -> bool {
+        false
+    }
+}
+
+/// Any closure taking an event can be used as a tracer, e.g. to print steps as they happen.
+impl<F: FnMut(Event)> Tracer for F {
+    fn record(&mut self, event: Event) {
+        self(event)
+    }
+}
+
+/// Keeps all recorded steps, so they can be explained or replayed later.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Recorder {
+    events: Vec<Event>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Renders steps as a numbered list, one per line.
+    pub fn explain(&self) -> String {
+        use core::fmt::Write;
+
+        let mut out = String::new();
+        for (i, event) in self.events.iter().enumerate() {
+            let _ = writeln!(out, "{}. {event}", i + 1);
+        }
+        out
+    }
+
+    /// Feeds recorded steps into another tracer, in the original order.
+    pub fn replay<T: Tracer + ?Sized>(&self, tracer: &mut T) {
+        self.events.iter().cloned().for_each(|e| tracer.record(e));
+    }
+}
+
+impl Tracer for Recorder {
+    fn record(&mut self, event: Event) {
+        self.events.push(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn event_display_test() {
+        let relax = Event::Relax {
+            from: "a".into(),
+            to: "b".into(),
+            old_cost: Some(5),
+            new_cost: 4,
+        };
+        assert_eq!(relax.to_string(), "relaxed edge a→b, cost 5→4");
+        let reach = Event::Relax {
+            from: "a".into(),
+            to: "b".into(),
+            old_cost: None,
+            new_cost: 4,
+        };
+        assert_eq!(reach.to_string(), "reached b via a, cost ∞→4");
+    }
+
+    #[test]
+    fn recorder_test() {
+        let mut recorder = Recorder::new();
+        recorder.record(Event::Note("start".into()));
+        recorder.record(Event::Iteration {
+            index: 1,
+            value: 1.5,
+        });
+        assert_eq!(
+            recorder.explain(),
+            "1. start\n2. iteration 1, estimate 1.5\n"
+        );
+
+        let mut replayed = Vec::new();
+        recorder.replay(&mut |e: Event| replayed.push(e));
+        assert_eq!(replayed, recorder.events());
+        assert!(recorder.enabled());
+        assert!(!NoTrace.enabled());
+    }
+}